@@ -2,16 +2,16 @@
 
 $resource = <<<'FTL'
     # Select expression only allows string/numeric types as selector.
-    # We will use STR function to convert the boolean output of LT to string.
-    log-time = User { $user->name} logged in at { $date->format("Y-m-d G:i:a") } {STR(LT($date, $deadline)) ->
+    # The LT builtin returns "true" or "false", so it can be used directly.
+    log-time = User { $user->name} logged in at { $date->format("Y-m-d G:i:a") } {LT($date, $deadline) ->
             [true] before
             *[false] after
         } deadline.
     FTL;
 
 /**
-  * Pre-process the FTL to replace PHP style property access and
-  * method call with Fluent functions.
+  * Pre-process the FTL to replace PHP style property access with the
+  * PROP builtin and method calls with a Fluent function.
   */
 function preProcess(string $resource): string
 {
@@ -20,20 +20,14 @@ function preProcess(string $resource): string
             '~(\$[A-Za-z][A-Za-z0-9_]*)->([A-Za-z][A-Za-z0-9_]*)\(~',
             '~(\$[A-Za-z][A-Za-z0-9_]*)->([A-Za-z][A-Za-z0-9_]*)~',
         ],
-        ['OBJ_METHOD(\1, "\2", ', 'OBJ_PROP(\1, "\2")'],
+        ['OBJ_METHOD(\1, "\2", ', 'PROP(\1, "\2")'],
         $resource
     );
 }
 
 try {
     $bundle = new FluentPhp\FluentBundle('en');
-    $bundle->addFunction('EQ', fn ($val1, $val2) => $val1 == $val2);
-    $bundle->addFunction('GT', fn ($val1, $val2) => $val1 > $val2);
-    $bundle->addFunction('LT', fn ($val1, $val2) => $val1 < $val2);
-    $bundle->addFunction('GTE', fn ($val1, $val2) => $val1 >= $val2);
-    $bundle->addFunction('LTE', fn ($val1, $val2) => $val1 <= $val2);
-    $bundle->addFunction('STR', fn ($val) => is_bool($val) ? ($val ? "true" : "false") : (string)$val);
-    $bundle->addFunction('OBJ_PROP', fn ($obj, $prop) => $obj->{$prop});
+    $bundle->addBuiltins(['compare']);
     $bundle->addFunction('OBJ_METHOD', fn ($obj, $method, ...$args) => $obj->{$method}(...$args));
 
     $bundle->addResource(preProcess($resource));
@@ -0,0 +1,319 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use fixed_decimal::{FixedDecimal, FloatPrecision, Sign};
+use fluent::{FluentBundle, FluentError, FluentValue};
+use icu_casemap::CaseMapper;
+use icu_casemap::titlecase::{TitlecaseOptions, TrailingCase};
+use icu_locid::LanguageIdentifier;
+
+use crate::{FluentPhpBoolValue, FluentPhpDateTimeValue, locale, number};
+
+/// An opt-in group of builtins, added to a bundle with `addBuiltins()`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Group {
+    /// `EQ`, `GT`, `LT`, `GTE` and `LTE`.
+    Compare,
+    /// `STR`, `UPPER`, `LOWER`, `CAPITALIZE`, `TRIM` and `LENGTH`.
+    String,
+}
+
+impl Group {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "compare" => Some(Self::Compare),
+            "string" => Some(Self::String),
+            _ => None,
+        }
+    }
+
+    /// The names of the group's functions.
+    pub fn names(self) -> &'static [&'static str] {
+        match self {
+            Self::Compare => &["EQ", "GT", "LT", "GTE", "LTE"],
+            Self::String => &["STR", "UPPER", "LOWER", "CAPITALIZE", "TRIM", "LENGTH"],
+        }
+    }
+
+    /// Register the group's functions. Fails like `add_function()` if one of
+    /// the names is taken.
+    pub fn add_to<R>(self, bundle: &mut FluentBundle<R>) -> Result<(), FluentError> {
+        let lang = bundle_lang(bundle);
+        for name in self.names() {
+            let lang = lang.clone();
+            bundle.add_function(name, move |positional, _| call(name, &lang, positional))?;
+        }
+        Ok(())
+    }
+}
+
+/// The language locale-aware builtins use in `bundle`: its first locale,
+/// which a bundle keeps for its lifetime.
+pub fn bundle_lang<R>(bundle: &FluentBundle<R>) -> LanguageIdentifier {
+    bundle
+        .locales
+        .first()
+        .map(|lang| locale::to_icu_locale(lang).id)
+        .unwrap_or_default()
+}
+
+/// Call the builtin `name` for a bundle whose locale is `lang`. Names outside
+/// every group are an error.
+pub fn call<'a>(
+    name: &str,
+    lang: &LanguageIdentifier,
+    positional: &[FluentValue<'a>],
+) -> FluentValue<'a> {
+    match name {
+        "EQ" => compare(positional, Ordering::is_eq),
+        "GT" => compare(positional, Ordering::is_gt),
+        "LT" => compare(positional, Ordering::is_lt),
+        "GTE" => compare(positional, Ordering::is_ge),
+        "LTE" => compare(positional, Ordering::is_le),
+        "STR" => map_text(positional, str::to_string),
+        "UPPER" => map_text(positional, |text| {
+            CaseMapper::new().uppercase_to_string(text, lang)
+        }),
+        "LOWER" => map_text(positional, |text| {
+            CaseMapper::new().lowercase_to_string(text, lang)
+        }),
+        "CAPITALIZE" => map_text(positional, |text| capitalize(text, lang)),
+        "TRIM" => map_text(positional, |text| text.trim().to_string()),
+        "LENGTH" => match positional.first().and_then(text) {
+            Some(text) => FluentValue::from(text.chars().count()),
+            None => FluentValue::Error,
+        },
+        _ => FluentValue::Error,
+    }
+}
+
+// -- Comparisons --
+
+/// A value as the comparison builtins see it.
+#[derive(Debug)]
+enum Operand {
+    Number(FixedDecimal),
+    Text(String),
+    /// Seconds since the Unix epoch.
+    Instant(i64),
+}
+
+impl Operand {
+    /// Numbers, numeric strings and dates compare by value; other strings and
+    /// booleans by their text. `None` for values that cannot be compared.
+    fn new(value: &FluentValue) -> Option<Self> {
+        match value {
+            FluentValue::String(s) => Some(
+                FixedDecimal::from_str(s.trim())
+                    .map(Self::Number)
+                    .unwrap_or_else(|_| Self::Text(s.to_string())),
+            ),
+            FluentValue::Number(n) => FixedDecimal::try_from_f64(n.value, FloatPrecision::Floating)
+                .ok()
+                .map(Self::Number),
+            FluentValue::Custom(custom) => {
+                let custom = custom.as_ref().as_any();
+                if let Some(decimal) = custom.downcast_ref::<number::FluentDecimal>() {
+                    Some(Self::Number(decimal.value.clone()))
+                } else if let Some(datetime) = custom.downcast_ref::<FluentPhpDateTimeValue>() {
+                    Some(Self::Instant(datetime.datetime.timestamp))
+                } else {
+                    custom
+                        .downcast_ref::<FluentPhpBoolValue>()
+                        .map(|value| Self::Text(value.stringify().into_owned()))
+                }
+            }
+            FluentValue::None | FluentValue::Error => None,
+        }
+    }
+
+    /// Like PHP 8, a number and a non-numeric string compare as strings.
+    fn cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::Number(a), Self::Number(b)) => Some(compare_decimals(a, b)),
+            (Self::Text(a), Self::Text(b)) => Some(a.cmp(b)),
+            (Self::Number(a), Self::Text(b)) => Some(a.to_string().cmp(b)),
+            (Self::Text(a), Self::Number(b)) => Some(a.cmp(&b.to_string())),
+            (Self::Instant(a), Self::Instant(b)) => Some(a.cmp(b)),
+            _ => None,
+        }
+    }
+}
+
+/// Compare the two arguments. The result is the string `"true"` or `"false"`
+/// rather than a boolean, so it can be used as a selector.
+fn compare<'a>(positional: &[FluentValue<'a>], test: fn(Ordering) -> bool) -> FluentValue<'a> {
+    let [left, right] = positional else {
+        return FluentValue::Error;
+    };
+    let ordering = Operand::new(left)
+        .zip(Operand::new(right))
+        .and_then(|(left, right)| left.cmp(&right));
+
+    match ordering {
+        Some(ordering) if test(ordering) => "true".into(),
+        Some(_) => "false".into(),
+        None => FluentValue::Error,
+    }
+}
+
+/// Compare decimals exactly, digit by digit, since `FixedDecimal` has no
+/// ordering of its own.
+fn compare_decimals(a: &FixedDecimal, b: &FixedDecimal) -> Ordering {
+    let (a_negative, a_integer, a_fraction) = decimal_parts(a);
+    let (b_negative, b_integer, b_fraction) = decimal_parts(b);
+    let magnitude = a_integer
+        .len()
+        .cmp(&b_integer.len())
+        .then_with(|| a_integer.cmp(&b_integer))
+        .then_with(|| a_fraction.cmp(&b_fraction));
+
+    match (a_negative, b_negative) {
+        (false, false) => magnitude,
+        (true, true) => magnitude.reverse(),
+        (false, true) => Ordering::Greater,
+        (true, false) => Ordering::Less,
+    }
+}
+
+/// The sign, integer digits without leading zeros and fraction digits without
+/// trailing zeros of `decimal`. Zero is never negative.
+fn decimal_parts(decimal: &FixedDecimal) -> (bool, String, String) {
+    let digits = decimal.to_string();
+    let digits = digits.trim_start_matches(['-', '+']);
+    let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    let integer = integer.trim_start_matches('0');
+    let fraction = fraction.trim_end_matches('0');
+    let negative = decimal.sign() == Sign::Negative && !(integer.is_empty() && fraction.is_empty());
+
+    (negative, integer.to_string(), fraction.to_string())
+}
+
+// -- Strings --
+
+/// The text of a string, number or boolean argument, as PHP's `(string)` cast
+/// gives it, except that booleans are `"true"` and `"false"`.
+fn text<'v>(value: &'v FluentValue) -> Option<Cow<'v, str>> {
+    match value {
+        FluentValue::String(s) => Some(Cow::Borrowed(s)),
+        FluentValue::Number(n) => Some(n.as_string()),
+        FluentValue::Custom(custom) => {
+            let custom = custom.as_ref().as_any();
+            if let Some(decimal) = custom.downcast_ref::<number::FluentDecimal>() {
+                Some(Cow::Owned(decimal.value.to_string()))
+            } else {
+                custom
+                    .downcast_ref::<FluentPhpBoolValue>()
+                    .map(FluentPhpBoolValue::stringify)
+            }
+        }
+        _ => None,
+    }
+}
+
+fn map_text<'a>(positional: &[FluentValue<'a>], map: impl Fn(&str) -> String) -> FluentValue<'a> {
+    match positional.first().and_then(text) {
+        Some(text) => map(&text).into(),
+        None => FluentValue::Error,
+    }
+}
+
+/// Titlecase the first letter, leaving the rest of the text unchanged.
+fn capitalize(text: &str, lang: &LanguageIdentifier) -> String {
+    let mut options = TitlecaseOptions::default();
+    options.trailing_case = TrailingCase::Unchanged;
+    CaseMapper::new().titlecase_segment_with_only_case_data_to_string(text, lang, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fluent::{FluentArgs, FluentResource};
+    use unic_langid::langid;
+
+    fn format(lang: unic_langid::LanguageIdentifier, source: &str, args: &FluentArgs) -> String {
+        let mut bundle = FluentBundle::new(vec![lang]);
+        bundle.set_use_isolating(false);
+        Group::Compare.add_to(&mut bundle).unwrap();
+        Group::String.add_to(&mut bundle).unwrap();
+        bundle
+            .add_resource(FluentResource::try_new(format!("msg = {}\n", source)).unwrap())
+            .unwrap();
+
+        let pattern = bundle.get_message("msg").unwrap().value().unwrap();
+        let mut errors = vec![];
+        bundle
+            .format_pattern(pattern, Some(args), &mut errors)
+            .into_owned()
+    }
+
+    #[test]
+    fn compares_numbers_exactly() {
+        let mut args = FluentArgs::new();
+        args.set("big", number::from_integer(9_007_199_254_740_993));
+        args.set("price", "19.90");
+
+        let cases = [
+            ("{ EQ(1, 1.0) }", "true"),
+            ("{ LT(2, 10) }", "true"),
+            ("{ LT(-3, -2.5) }", "true"),
+            ("{ GTE(-0, 0) }", "true"),
+            ("{ GT($big, 9007199254740992) }", "true"),
+            ("{ EQ($price, 19.9) }", "true"),
+            ("{ LTE(\"10\", \"9\") }", "false"),
+            ("{ LT(\"apple\", \"banana\") }", "true"),
+            ("{ EQ(1, \"one\") }", "false"),
+        ];
+        for (source, expected) in cases {
+            assert_eq!(format(langid!("en"), source, &args), expected, "{}", source);
+        }
+    }
+
+    #[test]
+    fn comparisons_drive_selectors() {
+        let mut args = FluentArgs::new();
+        args.set("count", 3);
+        let source = "{ GT($count, 2) ->\n    [true] many\n   *[false] few\n}";
+        assert_eq!(format(langid!("en"), source, &args), "many");
+    }
+
+    #[test]
+    fn maps_text_for_the_locale() {
+        let mut args = FluentArgs::new();
+        args.set("city", "istanbul");
+        args.set("padded", "  text \n");
+
+        assert_eq!(format(langid!("en"), "{ UPPER($city) }", &args), "ISTANBUL");
+        assert_eq!(format(langid!("tr"), "{ UPPER($city) }", &args), "İSTANBUL");
+        assert_eq!(format(langid!("en"), "{ LOWER(\"ÀB\") }", &args), "àb");
+        assert_eq!(
+            format(langid!("en"), "{ CAPITALIZE(\"hello World\") }", &args),
+            "Hello World"
+        );
+        assert_eq!(
+            format(langid!("en"), "[{ TRIM($padded) }]", &args),
+            "[text]"
+        );
+        assert_eq!(format(langid!("en"), "{ LENGTH(\"Ünïcode\") }", &args), "7");
+    }
+
+    #[test]
+    fn stringifies_values() {
+        let mut args = FluentArgs::new();
+        args.set(
+            "yes",
+            FluentValue::Custom(Box::new(FluentPhpBoolValue(true))),
+        );
+        args.set("big", number::from_integer(9_007_199_254_740_993));
+        args.set("price", 1234.5);
+
+        assert_eq!(format(langid!("en"), "{ STR($yes) }", &args), "true");
+        assert_eq!(
+            format(langid!("en"), "{ STR($big) } { STR($price) }", &args),
+            "9007199254740993 1234.5"
+        );
+        let source = "{ STR(LT(1, 2)) ->\n    [true] yes\n   *[false] no\n}";
+        assert_eq!(format(langid!("en"), source, &args), "yes");
+    }
+}
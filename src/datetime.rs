@@ -0,0 +1,223 @@
+use fluent::{FluentArgs, FluentValue};
+use icu_calendar::types::IsoSecond;
+use icu_calendar::{DateTime, Iso};
+use icu_datetime::ZonedDateTimeFormatter;
+use icu_datetime::options::length;
+use icu_timezone::{CustomTimeZone, GmtOffset};
+use intl_memoizer::{IntlLangMemoizer, Memoizable};
+use unic_langid::LanguageIdentifier;
+
+use crate::locale;
+
+// -- Values --
+
+/// An instant and the UTC offset it is displayed in, with its `DATETIME()`
+/// options.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FluentDateTime {
+    /// Seconds since the Unix epoch.
+    pub timestamp: i64,
+    /// Offset from UTC in seconds.
+    pub offset: i32,
+    pub options: FluentDateTimeOptions,
+}
+
+impl FluentDateTime {
+    pub fn new(timestamp: i64, offset: i32) -> Self {
+        Self {
+            timestamp,
+            offset,
+            options: FluentDateTimeOptions::default(),
+        }
+    }
+
+    /// Format for the memoizer's locale. Returns `None` for instants outside
+    /// the range the formatter supports (roughly the years -2100 to 6000).
+    pub fn format(&self, intls: &IntlLangMemoizer) -> Option<String> {
+        intls
+            .with_try_get::<DateTimeFormatter, _, _>(self.options, |formatter| {
+                formatter.format(self)
+            })
+            .ok()
+            .flatten()
+    }
+
+    /// The local date and time, in the ISO calendar.
+    fn local(&self) -> Option<DateTime<Iso>> {
+        let local = self.timestamp.checked_add(self.offset.into())?;
+        let minutes = i32::try_from(local.div_euclid(60)).ok()?;
+        let mut datetime = DateTime::from_minutes_since_local_unix_epoch(minutes);
+        datetime.time.second = IsoSecond::try_from(local.rem_euclid(60) as u8).ok()?;
+        Some(datetime)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DateTimeStyle {
+    Full,
+    Long,
+    Medium,
+    Short,
+}
+
+impl DateTimeStyle {
+    fn parse(style: &str) -> Option<Self> {
+        match style {
+            "full" => Some(Self::Full),
+            "long" => Some(Self::Long),
+            "medium" => Some(Self::Medium),
+            "short" => Some(Self::Short),
+            _ => None,
+        }
+    }
+}
+
+/// `DATETIME()` options. Without either style, the medium date is shown.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct FluentDateTimeOptions {
+    pub date_style: Option<DateTimeStyle>,
+    pub time_style: Option<DateTimeStyle>,
+}
+
+impl FluentDateTimeOptions {
+    /// Apply `dateStyle` and `timeStyle`; unknown styles are ignored, like
+    /// unknown `NUMBER()` options.
+    pub fn merge(&mut self, args: &FluentArgs) {
+        for (key, value) in args.iter() {
+            let FluentValue::String(value) = value else {
+                continue;
+            };
+            match key {
+                "dateStyle" => self.date_style = DateTimeStyle::parse(value).or(self.date_style),
+                "timeStyle" => self.time_style = DateTimeStyle::parse(value).or(self.time_style),
+                _ => {}
+            }
+        }
+    }
+
+    fn length(self) -> length::Bag {
+        let date = |style| match style {
+            DateTimeStyle::Full => length::Date::Full,
+            DateTimeStyle::Long => length::Date::Long,
+            DateTimeStyle::Medium => length::Date::Medium,
+            DateTimeStyle::Short => length::Date::Short,
+        };
+        let time = |style| match style {
+            DateTimeStyle::Full => length::Time::Full,
+            DateTimeStyle::Long => length::Time::Long,
+            DateTimeStyle::Medium => length::Time::Medium,
+            DateTimeStyle::Short => length::Time::Short,
+        };
+
+        match (self.date_style, self.time_style) {
+            (Some(d), Some(t)) => length::Bag::from_date_time_style(date(d), time(t)),
+            (None, Some(t)) => length::Bag::from_time_style(time(t)),
+            (Some(d), None) => length::Bag::from_date_style(date(d)),
+            (None, None) => length::Bag::from_date_style(length::Date::Medium),
+        }
+    }
+}
+
+// -- Formatter --
+
+/// A CLDR date/time formatter for one locale and set of styles, memoized per
+/// bundle. Dates are shown in the locale's default calendar.
+pub struct DateTimeFormatter(ZonedDateTimeFormatter);
+
+impl Memoizable for DateTimeFormatter {
+    type Args = FluentDateTimeOptions;
+    type Error = icu_datetime::DateTimeError;
+
+    fn construct(lang: LanguageIdentifier, options: Self::Args) -> Result<Self, Self::Error> {
+        let locale = locale::to_icu_locale(&lang);
+        ZonedDateTimeFormatter::try_new(
+            &(&locale).into(),
+            options.length().into(),
+            Default::default(),
+        )
+        .map(Self)
+    }
+}
+
+impl DateTimeFormatter {
+    fn format(&self, value: &FluentDateTime) -> Option<String> {
+        let datetime = value.local()?.to_any();
+        let offset = GmtOffset::try_from_offset_seconds(value.offset).ok()?;
+        self.0
+            .format_to_string(&datetime, &CustomTimeZone::new_with_offset(offset))
+            .ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unic_langid::langid;
+
+    // 2023-03-22T15:22:05+01:00
+    const TIMESTAMP: i64 = 1679494925;
+
+    fn format(lang: LanguageIdentifier, offset: i32, options: &[(&str, &str)]) -> String {
+        let mut args = FluentArgs::new();
+        for (key, value) in options {
+            args.set(*key, *value);
+        }
+
+        let mut datetime = FluentDateTime::new(TIMESTAMP, offset);
+        datetime.options.merge(&args);
+        datetime.format(&IntlLangMemoizer::new(lang)).unwrap()
+    }
+
+    #[test]
+    fn defaults_to_the_medium_date() {
+        assert_eq!(format(langid!("en"), 3600, &[]), "Mar 22, 2023");
+        assert_eq!(format(langid!("de"), 3600, &[]), "22.03.2023");
+        assert_eq!(format(langid!("fr"), 3600, &[]), "22 mars 2023");
+    }
+
+    #[test]
+    fn applies_styles() {
+        assert_eq!(
+            format(langid!("en"), 3600, &[("timeStyle", "short")]),
+            "3:22\u{202f}PM"
+        );
+        assert_eq!(
+            format(
+                langid!("de"),
+                3600,
+                &[("dateStyle", "full"), ("timeStyle", "long")]
+            ),
+            "Mittwoch, 22. März 2023, 15:22:05 GMT+01:00"
+        );
+        assert_eq!(
+            format(langid!("en"), 3600, &[("dateStyle", "loud")]),
+            "Mar 22, 2023"
+        );
+    }
+
+    #[test]
+    fn shows_local_time_for_the_offset() {
+        let options = [("dateStyle", "short"), ("timeStyle", "short")];
+        assert_eq!(
+            format(langid!("en"), 0, &options),
+            "3/22/23, 2:22\u{202f}PM"
+        );
+        assert_eq!(
+            format(langid!("en"), -9 * 3600, &options),
+            "3/22/23, 5:22\u{202f}AM"
+        );
+    }
+
+    #[test]
+    fn uses_the_locale_calendar() {
+        assert_eq!(format(langid!("th"), 3600, &[]), "22 มี.ค. 2566");
+    }
+
+    #[test]
+    fn handles_instants_before_the_epoch() {
+        let datetime = FluentDateTime::new(-1, 0);
+        let intls = IntlLangMemoizer::new(langid!("en"));
+        assert_eq!(datetime.format(&intls).unwrap(), "Dec 31, 1969");
+        assert_eq!(FluentDateTime::new(i64::MAX, 0).format(&intls), None);
+    }
+}
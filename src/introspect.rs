@@ -0,0 +1,379 @@
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+
+use fluent::FluentResource;
+use fluent_syntax::ast::{self, CallArguments, Entry, Expression, InlineExpression, Pattern};
+use fluent_syntax::parser;
+
+// -- Entry ids --
+
+/// The ids of a resource's messages, in source order.
+pub fn message_ids(resource: &FluentResource) -> Vec<&str> {
+    resource
+        .entries()
+        .filter_map(|entry| match entry {
+            Entry::Message(message) => Some(message.id.name),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The ids of a resource's terms as written in FTL, with their leading `-`.
+pub fn term_ids(resource: &FluentResource) -> Vec<String> {
+    resource
+        .entries()
+        .filter_map(|entry| match entry {
+            Entry::Term(term) => Some(format!("-{}", term.id.name)),
+            _ => None,
+        })
+        .collect()
+}
+
+pub fn find_message<'r>(
+    resource: &'r FluentResource,
+    id: &str,
+) -> Option<&'r ast::Message<&'r str>> {
+    resource.entries().find_map(|entry| match entry {
+        Entry::Message(message) if message.id.name == id => Some(message),
+        _ => None,
+    })
+}
+
+// -- Message info --
+
+/// What a message contains and references, across its value and attributes.
+/// References are listed once each, in order of first use.
+#[derive(Debug, Default, PartialEq)]
+pub struct MessageInfo {
+    pub attributes: Vec<String>,
+    /// Variable names, without the `$`.
+    pub variables: Vec<String>,
+    /// Message references such as `menu-save` or `menu-save.title`.
+    pub messages: Vec<String>,
+    /// Term references such as `-brand` or `-brand.gender`.
+    pub terms: Vec<String>,
+    /// The comment attached to the message, without the `#` markers.
+    pub comment: Option<String>,
+}
+
+impl MessageInfo {
+    /// Describe `message` from `resource`, with its comment from `comments`,
+    /// those of the same resource.
+    pub fn new(
+        resource: &FluentResource,
+        comments: &Comments,
+        message: &ast::Message<&str>,
+    ) -> Self {
+        let mut info = Self {
+            attributes: message
+                .attributes
+                .iter()
+                .map(|attribute| attribute.id.name.to_string())
+                .collect(),
+            comment: comments.get(resource, message.id.name).map(String::from),
+            ..Self::default()
+        };
+
+        let patterns = message
+            .value
+            .iter()
+            .chain(message.attributes.iter().map(|attribute| &attribute.value));
+        for pattern in patterns {
+            info.visit_pattern(pattern);
+        }
+        info
+    }
+
+    fn visit_pattern(&mut self, pattern: &Pattern<&str>) {
+        for element in &pattern.elements {
+            if let ast::PatternElement::Placeable { expression } = element {
+                self.visit_expression(expression);
+            }
+        }
+    }
+
+    fn visit_expression(&mut self, expression: &Expression<&str>) {
+        match expression {
+            Expression::Inline(inline) => self.visit_inline(inline),
+            Expression::Select { selector, variants } => {
+                self.visit_inline(selector);
+                for variant in variants {
+                    self.visit_pattern(&variant.value);
+                }
+            }
+        }
+    }
+
+    fn visit_inline(&mut self, inline: &InlineExpression<&str>) {
+        match inline {
+            InlineExpression::VariableReference { id } => push_unique(&mut self.variables, id.name),
+            InlineExpression::MessageReference { id, attribute } => {
+                push_unique(&mut self.messages, &reference(id.name, attribute.as_ref()));
+            }
+            InlineExpression::TermReference {
+                id,
+                attribute,
+                arguments,
+            } => {
+                let id = format!("-{}", id.name);
+                push_unique(&mut self.terms, &reference(&id, attribute.as_ref()));
+                if let Some(arguments) = arguments {
+                    self.visit_arguments(arguments);
+                }
+            }
+            InlineExpression::FunctionReference { arguments, .. } => {
+                self.visit_arguments(arguments);
+            }
+            InlineExpression::Placeable { expression } => self.visit_expression(expression),
+            InlineExpression::StringLiteral { .. } | InlineExpression::NumberLiteral { .. } => {}
+        }
+    }
+
+    fn visit_arguments(&mut self, arguments: &CallArguments<&str>) {
+        for argument in &arguments.positional {
+            self.visit_inline(argument);
+        }
+        for argument in &arguments.named {
+            self.visit_inline(&argument.value);
+        }
+    }
+}
+
+// -- Argument uses --
+
+/// How a pattern uses the arguments it is formatted with: the variables it
+/// reads other than as the first argument of `KEY()`, and the messages it
+/// references, which format with the same arguments. Term bodies are not
+/// followed, since terms only see the arguments they are passed.
+#[derive(Debug, Default, PartialEq)]
+pub struct ArgumentUses<'s> {
+    pub variables: Vec<&'s str>,
+    /// Message ids with the attribute referenced, if any.
+    pub messages: Vec<(&'s str, Option<&'s str>)>,
+}
+
+impl<'s> ArgumentUses<'s> {
+    pub fn new(pattern: &Pattern<&'s str>) -> Self {
+        let mut uses = Self::default();
+        uses.visit_pattern(pattern);
+        uses
+    }
+
+    fn visit_pattern(&mut self, pattern: &Pattern<&'s str>) {
+        for element in &pattern.elements {
+            if let ast::PatternElement::Placeable { expression } = element {
+                self.visit_expression(expression);
+            }
+        }
+    }
+
+    fn visit_expression(&mut self, expression: &Expression<&'s str>) {
+        match expression {
+            Expression::Inline(inline) => self.visit_inline(inline),
+            Expression::Select { selector, variants } => {
+                self.visit_inline(selector);
+                for variant in variants {
+                    self.visit_pattern(&variant.value);
+                }
+            }
+        }
+    }
+
+    fn visit_inline(&mut self, inline: &InlineExpression<&'s str>) {
+        match inline {
+            InlineExpression::VariableReference { id } => {
+                if !self.variables.contains(&id.name) {
+                    self.variables.push(id.name);
+                }
+            }
+            InlineExpression::MessageReference { id, attribute } => {
+                let reference = (id.name, attribute.as_ref().map(|attribute| attribute.name));
+                if !self.messages.contains(&reference) {
+                    self.messages.push(reference);
+                }
+            }
+            InlineExpression::TermReference { arguments, .. } => {
+                if let Some(arguments) = arguments {
+                    self.visit_arguments(arguments, false);
+                }
+            }
+            InlineExpression::FunctionReference { id, arguments } => {
+                self.visit_arguments(arguments, id.name == "KEY");
+            }
+            InlineExpression::Placeable { expression } => self.visit_expression(expression),
+            InlineExpression::StringLiteral { .. } | InlineExpression::NumberLiteral { .. } => {}
+        }
+    }
+
+    /// Visit call arguments; `keyed` skips a variable read by `KEY()`.
+    fn visit_arguments(&mut self, arguments: &CallArguments<&'s str>, keyed: bool) {
+        for (index, argument) in arguments.positional.iter().enumerate() {
+            if keyed && index == 0 {
+                if let InlineExpression::VariableReference { .. } = argument {
+                    continue;
+                }
+            }
+            self.visit_inline(argument);
+        }
+        for argument in &arguments.named {
+            self.visit_inline(&argument.value);
+        }
+    }
+}
+
+fn reference(id: &str, attribute: Option<&ast::Identifier<&str>>) -> String {
+    match attribute {
+        Some(attribute) => format!("{}.{}", id, attribute.name),
+        None => id.to_string(),
+    }
+}
+
+fn push_unique(items: &mut Vec<String>, item: &str) {
+    if !items.iter().any(|existing| existing == item) {
+        items.push(item.to_string());
+    }
+}
+
+// -- Comments --
+
+/// The comments attached to a resource's messages, by message id. The
+/// resource keeps no comments, so its source is parsed again for them, once,
+/// when one is first asked for. Clones share the parsed comments.
+#[derive(Clone, Debug, Default)]
+pub struct Comments(Arc<OnceLock<HashMap<String, String>>>);
+
+impl Comments {
+    /// The comment attached to message `id` of `resource`, which must be the
+    /// resource these are the comments of.
+    pub fn get(&self, resource: &FluentResource, id: &str) -> Option<&str> {
+        self.0
+            .get_or_init(|| parse_comments(resource))
+            .get(id)
+            .map(String::as_str)
+    }
+}
+
+/// The comments attached to messages, from the full AST of the resource.
+fn parse_comments(resource: &FluentResource) -> HashMap<String, String> {
+    let ast = parser::parse(resource.source()).unwrap_or_else(|(ast, _)| ast);
+    ast.body
+        .into_iter()
+        .filter_map(|entry| match entry {
+            Entry::Message(message) => {
+                let comment = message.comment?.content.join("\n");
+                Some((message.id.name.to_string(), comment))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"
+### Resource comment
+
+-brand = Firefox
+    .gender = masculine
+
+## Group comment
+
+# Shown on the toolbar.
+# Keep it short.
+save = Save { $file } with { -brand }
+    .title = { save-hint.tooltip } { $file }
+    .accesskey = S
+
+save-hint = { $count ->
+    [one] { NUMBER($count) } file by { $user }
+   *[other] { $count } files
+}
+    .tooltip = { -brand.gender ->
+        [masculine] His
+       *[other] Their
+    } { -app(tone: "formal") } { $version }
+
+-app = App
+"#;
+
+    fn resource() -> FluentResource {
+        FluentResource::try_new(SOURCE.to_string()).unwrap()
+    }
+
+    fn strings(items: &[&str]) -> Vec<String> {
+        items.iter().map(|item| item.to_string()).collect()
+    }
+
+    #[test]
+    fn lists_message_and_term_ids_in_source_order() {
+        let resource = resource();
+
+        assert_eq!(message_ids(&resource), ["save", "save-hint"]);
+        assert_eq!(term_ids(&resource), ["-brand", "-app"]);
+    }
+
+    #[test]
+    fn describes_references_across_value_and_attributes() {
+        let resource = resource();
+        let save = find_message(&resource, "save").unwrap();
+
+        assert_eq!(
+            MessageInfo::new(&resource, &Comments::default(), save),
+            MessageInfo {
+                attributes: strings(&["title", "accesskey"]),
+                variables: strings(&["file"]),
+                messages: strings(&["save-hint.tooltip"]),
+                terms: strings(&["-brand"]),
+                comment: Some("Shown on the toolbar.\nKeep it short.".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn walks_selectors_variants_and_arguments() {
+        let resource = resource();
+        let hint = find_message(&resource, "save-hint").unwrap();
+        let info = MessageInfo::new(&resource, &Comments::default(), hint);
+
+        assert_eq!(info.variables, strings(&["count", "user", "version"]));
+        assert_eq!(info.terms, strings(&["-brand.gender", "-app"]));
+        assert_eq!(info.comment, None);
+        assert!(find_message(&resource, "brand").is_none());
+    }
+
+    #[test]
+    fn parses_comments_once_for_all_clones() {
+        let resource = resource();
+        let comments = Comments::default();
+        let shared = comments.clone();
+
+        assert!(shared.0.get().is_none());
+        assert_eq!(
+            comments.get(&resource, "save"),
+            Some("Shown on the toolbar.\nKeep it short.")
+        );
+        assert!(shared.0.get().is_some());
+        assert_eq!(shared.get(&resource, "save-hint"), None);
+    }
+
+    #[test]
+    fn finds_argument_uses_outside_key() {
+        let resource = FluentResource::try_new(
+            "msg = { KEY($data, \"a\") } { KEY(KEY($nested, \"a\"), $key) } { $data }\n\
+             keyed = { KEY($only, \"a\") } { -app($term) } { save.title } { save }\n"
+                .to_string(),
+        )
+        .unwrap();
+
+        let msg = find_message(&resource, "msg").unwrap();
+        let uses = ArgumentUses::new(msg.value.as_ref().unwrap());
+        assert_eq!(uses.variables, ["key", "data"]);
+
+        let keyed = find_message(&resource, "keyed").unwrap();
+        let uses = ArgumentUses::new(keyed.value.as_ref().unwrap());
+        assert_eq!(uses.variables, ["term"]);
+        assert_eq!(uses.messages, [("save", Some("title")), ("save", None)]);
+    }
+}
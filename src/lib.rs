@@ -2,24 +2,42 @@
 
 use ext_php_rs::boxed::ZBox;
 use ext_php_rs::convert::{FromZval, IntoZval, IntoZvalDyn};
-use ext_php_rs::flags::{DataType, IniEntryPermission};
-use ext_php_rs::types::{ZendHashTable, Zval};
+use ext_php_rs::error::php_error;
+use ext_php_rs::flags::{DataType, ErrorType, IniEntryPermission};
+use ext_php_rs::types::{ZendHashTable, ZendObject, Zval};
 use ext_php_rs::{
     info_table_end, info_table_row, info_table_start,
     prelude::*,
-    zend::{ExecutorGlobals, IniEntryDef, ModuleEntry, ce},
+    zend::{ClassEntry, ExecutorGlobals, IniEntryDef, ModuleEntry, ce},
 };
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt::{Debug, Display, Formatter};
 use std::ops::{Deref, Range};
 use std::sync::Arc;
 
-use fluent::types::FluentType;
-use fluent::{FluentArgs, FluentBundle, FluentError, FluentResource, FluentValue};
+use fluent::resolver::{ResolverError, errors::ReferenceKind};
+use fluent::types::{FluentNumber, FluentType};
+use fluent::{FluentArgs, FluentBundle, FluentError, FluentMessage, FluentResource, FluentValue};
+use fluent_syntax::ast::Pattern;
 use fluent_syntax::parser::ParserError;
+use intl_memoizer::IntlLangMemoizer;
+use intl_pluralrules::PluralRuleType;
+use intl_pluralrules::operands::PluralOperands;
 use std::sync::{Mutex, MutexGuard};
 use unic_langid::LanguageIdentifier;
 
+mod builtins;
 mod cache;
+mod datetime;
+mod introspect;
+mod list;
+mod locale;
+mod number;
+mod plural;
+mod pseudo;
+mod selector;
 
 // -- Exception classes --
 
@@ -72,12 +90,24 @@ impl ResolverException {
     }
 }
 
+impl ResolverException {
+    fn new_object(message: String, errors: Vec<String>) -> Zval {
+        Self { message, errors }.into_zval(true).unwrap()
+    }
+}
+
 #[php_class]
 #[php(name = "FluentPhp\\CacheException")]
 #[php(extends(Exception))]
 #[derive(Default)]
 struct CacheException;
 
+#[php_class]
+#[php(name = "FluentPhp\\AttributeNotFoundException")]
+#[php(extends(Exception))]
+#[derive(Default)]
+struct AttributeNotFoundException;
+
 // -- Internal error types --
 
 #[derive(Debug)]
@@ -85,7 +115,11 @@ enum FluentPhpError {
     ParseError(Vec<FluentPhpParseError>),
     ResolverError {
         msg_id: String,
-        errors: Vec<FluentError>,
+        errors: FormatErrors,
+    },
+    AttributeNotFound {
+        msg_id: String,
+        attr_id: String,
     },
     Message(String),
 }
@@ -127,6 +161,62 @@ fn resolver_inner(e: &FluentError) -> String {
     }
 }
 
+/// Errors collected while formatting one or more patterns.
+#[derive(Debug, Default)]
+struct FormatErrors {
+    resolver: Vec<FluentError>,
+    exceptions: Vec<FunctionException>,
+}
+
+impl FormatErrors {
+    fn is_empty(&self) -> bool {
+        self.resolver.is_empty() && self.exceptions.is_empty()
+    }
+
+    fn messages(&self) -> Vec<String> {
+        self.resolver
+            .iter()
+            .map(resolver_inner)
+            .chain(self.exceptions.iter().map(|e| e.description.clone()))
+            .collect()
+    }
+}
+
+/// An exception thrown by a PHP function while a pattern was formatted.
+struct FunctionException {
+    /// E.g. `UPPER() threw RuntimeException: boom`.
+    description: String,
+    exception: ZBox<ZendObject>,
+}
+
+impl FunctionException {
+    fn new(function: &str, exception: ZBox<ZendObject>) -> Self {
+        let class = exception.get_class_name().unwrap_or_default();
+        let message = exception
+            .try_call_method("getMessage", vec![])
+            .ok()
+            .and_then(|message| message.string())
+            .unwrap_or_default();
+
+        Self {
+            description: format!("{}() threw {}: {}", function, class, message),
+            exception,
+        }
+    }
+
+    fn into_zval(self) -> Zval {
+        self.exception.into_zval(false).unwrap()
+    }
+}
+
+impl Debug for FunctionException {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FunctionException")
+            .field("description", &self.description)
+            .finish_non_exhaustive()
+    }
+}
+
 impl Display for FluentPhpError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -142,26 +232,35 @@ impl Display for FluentPhpError {
                 }
             }
             FluentPhpError::ResolverError { msg_id, errors } => {
-                let count = errors.len();
-                let label = if count == 1 {
-                    format!("Resolution failed for message \"{}\" with error: ", msg_id)
-                } else {
-                    format!(
-                        "Resolution failed for message \"{}\" with {} errors: ",
-                        msg_id, count
-                    )
-                };
-                let mut parts: Vec<String> = errors.iter().take(3).map(resolver_inner).collect();
-                if count > 3 {
-                    parts.push(format!("and {} more", count - 3));
-                }
-                write!(f, "{}{}", label, parts.join("; "))
+                write!(f, "{}", resolver_message(msg_id, errors.messages()))
             }
+            FluentPhpError::AttributeNotFound { msg_id, attr_id } => write!(
+                f,
+                "Attribute \"{}\" not found on message \"{}\".",
+                attr_id, msg_id
+            ),
             FluentPhpError::Message(err) => write!(f, "{}", &err),
         }
     }
 }
 
+fn resolver_message(msg_id: &str, errors: Vec<String>) -> String {
+    let count = errors.len();
+    let label = if count == 1 {
+        format!("Resolution failed for message \"{}\" with error: ", msg_id)
+    } else {
+        format!(
+            "Resolution failed for message \"{}\" with {} errors: ",
+            msg_id, count
+        )
+    };
+    let mut parts: Vec<String> = errors.into_iter().take(3).collect();
+    if count > 3 {
+        parts.push(format!("and {} more", count - 3));
+    }
+    format!("{}{}", label, parts.join("; "))
+}
+
 impl From<FluentPhpError> for PhpException {
     fn from(exception: FluentPhpError) -> Self {
         let message = format!("{}", exception);
@@ -177,22 +276,137 @@ impl From<FluentPhpError> for PhpException {
                 };
                 PhpException::default(message).with_object(obj.into_zval(true).unwrap())
             }
-            FluentPhpError::ResolverError {
-                errors: fluent_errors,
-                ..
-            } => {
-                let errors = fluent_errors.iter().map(resolver_inner).collect();
-                let obj = ResolverException {
-                    message: message.clone(),
-                    errors,
-                };
-                PhpException::default(message).with_object(obj.into_zval(true).unwrap())
+            // Exceptions thrown by PHP functions are chained where the error
+            // is thrown, in `FluentPhpBundle::recover_resolver_error()`.
+            FluentPhpError::ResolverError { errors, .. } => PhpException::default(message.clone())
+                .with_object(ResolverException::new_object(message, errors.messages())),
+            FluentPhpError::AttributeNotFound { .. } => {
+                PhpException::from_class::<AttributeNotFoundException>(message)
             }
             _ => PhpException::from_class::<Exception>(message),
         }
     }
 }
 
+// -- Error policy --
+
+/// What formatting does when a message, value or attribute is missing, or a
+/// pattern fails to resolve.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+enum ErrorPolicy {
+    /// Throw the exception.
+    #[default]
+    Throw,
+    /// Emit an E_WARNING and return the partial output (or the id).
+    Warn,
+    /// Silently return the message id.
+    Fallback,
+}
+
+impl ErrorPolicy {
+    fn parse(policy: &str) -> Option<Self> {
+        let policy = policy.trim();
+        if policy.eq_ignore_ascii_case("throw") {
+            Some(Self::Throw)
+        } else if policy.eq_ignore_ascii_case("warn") {
+            Some(Self::Warn)
+        } else if policy.eq_ignore_ascii_case("fallback") {
+            Some(Self::Fallback)
+        } else {
+            None
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Throw => "throw",
+            Self::Warn => "warn",
+            Self::Fallback => "fallback",
+        }
+    }
+
+    fn from_php(policy: &str) -> PhpResult<Self> {
+        Self::parse(policy).ok_or_else(|| {
+            PhpException::from_class::<Exception>(format!(
+                "Unknown error policy \"{}\"; expected \"throw\", \"warn\" or \"fallback\".",
+                policy
+            ))
+        })
+    }
+
+    /// Throw `error` under `throw`. Otherwise report it (as an E_WARNING under
+    /// `warn`) and return, so the caller can degrade.
+    fn recover(self, error: FluentPhpError) -> PhpResult<()> {
+        match self {
+            Self::Throw => Err(error.into()),
+            Self::Warn => {
+                php_error(&ErrorType::Warning, &error.to_string());
+                Ok(())
+            }
+            Self::Fallback => Ok(()),
+        }
+    }
+}
+
+/// How an exception thrown by a PHP function surfaces when formatting throws.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+enum FunctionExceptionMode {
+    /// Throw a ResolverException with the function's exception as `previous`.
+    #[default]
+    Wrap,
+    /// Rethrow the function's exception itself.
+    Rethrow,
+}
+
+impl FunctionExceptionMode {
+    fn from_php(mode: &str) -> PhpResult<Self> {
+        if mode.eq_ignore_ascii_case("wrap") {
+            Ok(Self::Wrap)
+        } else if mode.eq_ignore_ascii_case("rethrow") {
+            Ok(Self::Rethrow)
+        } else {
+            Err(PhpException::from_class::<Exception>(format!(
+                "Unknown function exception mode \"{}\"; expected \"wrap\" or \"rethrow\".",
+                mode
+            )))
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Wrap => "wrap",
+            Self::Rethrow => "rethrow",
+        }
+    }
+}
+
+fn plural_rule_type(rule_type: Option<String>) -> PhpResult<PluralRuleType> {
+    let Some(rule_type) = rule_type else {
+        return Ok(PluralRuleType::CARDINAL);
+    };
+
+    plural::parse_type(&rule_type).ok_or_else(|| {
+        PhpException::from_class::<Exception>(format!(
+            "Unknown plural rule type \"{}\"; expected \"cardinal\" or \"ordinal\".",
+            rule_type
+        ))
+    })
+}
+
+fn builtin_groups(groups: &[String]) -> PhpResult<Vec<builtins::Group>> {
+    groups
+        .iter()
+        .map(|name| {
+            builtins::Group::parse(name).ok_or_else(|| {
+                PhpException::from_class::<Exception>(format!(
+                    "Unknown builtin group \"{}\"; expected \"compare\" or \"string\".",
+                    name
+                ))
+            })
+        })
+        .collect()
+}
+
 fn cache_error_to_php(e: cache::CacheError) -> PhpException {
     match e {
         cache::CacheError::LockPoisoned => PhpException::from_class::<CacheException>(
@@ -217,6 +431,25 @@ fn cache_file_error_to_php(path: &str, e: cache::CacheError) -> PhpException {
     }
 }
 
+fn locale_error_to_php(e: locale::LocaleError) -> PhpException {
+    let message = match e {
+        locale::LocaleError::InvalidLanguageIdentifier {
+            tag,
+            subtag,
+            position,
+            expected,
+        } => format!(
+            "Invalid language identifier \"{}\": subtag \"{}\" at position {} is not a valid {}.",
+            tag, subtag, position, expected
+        ),
+        locale::LocaleError::UnknownStrategy(strategy) => format!(
+            "Unknown negotiation strategy \"{}\"; expected \"filtering\", \"matching\" or \"lookup\".",
+            strategy
+        ),
+    };
+    PhpException::from_class::<Exception>(message)
+}
+
 // -- Parse error detail --
 
 fn line_offset_from_range(str: &str, range: &Range<usize>) -> Option<(u32, usize)> {
@@ -275,22 +508,67 @@ impl FluentPhpParseError {
 #[php(name = "FluentPhp\\FluentResource")]
 struct FluentPhpResource {
     inner: Arc<FluentResource>,
+    comments: introspect::Comments,
 }
 
 #[php_impl]
 impl FluentPhpResource {
     pub fn from_string(source: String) -> PhpResult<Self> {
         let inner = cache::uncached_parse_string(source).map_err(cache_error_to_php)?;
-        Ok(Self { inner })
+        Ok(Self::new(inner))
     }
 
     pub fn from_file(path: String) -> PhpResult<Self> {
         let inner =
             cache::uncached_parse_file(&path).map_err(|e| cache_file_error_to_php(&path, e))?;
-        Ok(Self { inner })
+        Ok(Self::new(inner))
+    }
+
+    pub fn get_message_ids(&self) -> Vec<String> {
+        introspect::message_ids(&self.inner)
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+
+    /// Term ids with their leading `-`, as written in FTL.
+    pub fn get_term_ids(&self) -> Vec<String> {
+        introspect::term_ids(&self.inner)
+    }
+
+    pub fn get_message_info(&self, msg_id: String) -> Option<ZBox<ZendHashTable>> {
+        message_info(&self.inner, &self.comments, &msg_id)
+    }
+}
+
+impl FluentPhpResource {
+    fn new(inner: Arc<FluentResource>) -> Self {
+        Self {
+            inner,
+            comments: introspect::Comments::default(),
+        }
     }
 }
 
+/// The `getMessageInfo()` array for message `id` of `resource`, or `None` if
+/// the resource has no such message.
+fn message_info(
+    resource: &FluentResource,
+    comments: &introspect::Comments,
+    id: &str,
+) -> Option<ZBox<ZendHashTable>> {
+    let message = introspect::find_message(resource, id)?;
+    let info = introspect::MessageInfo::new(resource, comments, message);
+
+    let mut ht = ZendHashTable::new();
+    ht.insert("attributes", info.attributes).unwrap();
+    ht.insert("variables", info.variables).unwrap();
+    ht.insert("messages", info.messages).unwrap();
+    ht.insert("terms", info.terms).unwrap();
+    ht.insert("comment", info.comment).unwrap();
+    Some(ht)
+}
+
 // -- ResourceCache PHP class --
 
 #[php_class]
@@ -302,13 +580,13 @@ struct ResourceCache;
 impl ResourceCache {
     pub fn from_string(source: String) -> PhpResult<FluentPhpResource> {
         let inner = cache::get_or_parse_string(source).map_err(cache_error_to_php)?;
-        Ok(FluentPhpResource { inner })
+        Ok(FluentPhpResource::new(inner))
     }
 
     pub fn from_file(path: String) -> PhpResult<FluentPhpResource> {
         let inner =
             cache::get_or_parse_file(&path).map_err(|e| cache_file_error_to_php(&path, e))?;
-        Ok(FluentPhpResource { inner })
+        Ok(FluentPhpResource::new(inner))
     }
 
     pub fn invalidate_file(path: String) -> PhpResult<bool> {
@@ -340,32 +618,214 @@ impl ResourceCache {
     }
 }
 
+// -- Locale PHP class --
+
+#[php_class]
+#[php(name = "FluentPhp\\Locale")]
+struct Locale {
+    lang_id: LanguageIdentifier,
+}
+
+#[php_impl]
+impl Locale {
+    fn __construct(tag: String) -> PhpResult<Self> {
+        let lang_id = locale::parse_language_identifier(&tag).map_err(locale_error_to_php)?;
+        Ok(Self { lang_id })
+    }
+
+    pub fn get_language(&self) -> String {
+        self.lang_id.language.as_str().to_string()
+    }
+
+    pub fn get_script(&self) -> Option<String> {
+        self.lang_id
+            .script
+            .map(|script| script.as_str().to_string())
+    }
+
+    pub fn get_region(&self) -> Option<String> {
+        self.lang_id
+            .region
+            .map(|region| region.as_str().to_string())
+    }
+
+    pub fn get_variants(&self) -> Vec<String> {
+        self.lang_id
+            .variants()
+            .map(|variant| variant.as_str().to_string())
+            .collect()
+    }
+
+    #[php(name = "__toString")]
+    pub fn __to_string(&self) -> String {
+        self.lang_id.to_string()
+    }
+
+    #[php(optional = self_as_range)]
+    pub fn matches(
+        &self,
+        other: &Zval,
+        self_as_range: Option<bool>,
+        other_as_range: Option<bool>,
+    ) -> PhpResult<bool> {
+        let other = if other.is_string() {
+            let tag = other.string().unwrap_or_default();
+            locale::parse_language_identifier(&tag).map_err(locale_error_to_php)?
+        } else {
+            other
+                .object()
+                .and_then(|obj| obj.extract::<&Locale>().ok())
+                .map(|other| other.lang_id.clone())
+                .ok_or_else(|| {
+                    PhpException::from_class::<Exception>(
+                        "matches() expects a string or Locale instance.".to_string(),
+                    )
+                })?
+        };
+
+        Ok(self.lang_id.matches(
+            &other,
+            self_as_range.unwrap_or(false),
+            other_as_range.unwrap_or(false),
+        ))
+    }
+
+    pub fn maximize(&self) -> Self {
+        let mut lang_id = self.lang_id.clone();
+        lang_id.maximize();
+        Self { lang_id }
+    }
+
+    pub fn minimize(&self) -> Self {
+        let mut lang_id = self.lang_id.clone();
+        lang_id.minimize();
+        Self { lang_id }
+    }
+
+    #[php(optional = strategy)]
+    pub fn negotiate(
+        requested: Vec<String>,
+        available: Vec<String>,
+        default: String,
+        strategy: Option<String>,
+    ) -> PhpResult<Vec<String>> {
+        let strategy = match strategy {
+            Some(strategy) => locale::parse_strategy(&strategy).map_err(locale_error_to_php)?,
+            None => fluent_langneg::NegotiationStrategy::Filtering,
+        };
+
+        locale::negotiate(&requested, &available, &default, strategy).map_err(locale_error_to_php)
+    }
+
+    pub fn parse_accept_language(header: String) -> Vec<String> {
+        locale::parse_accept_language(&header)
+    }
+}
+
 // -- Zval / FluentValue conversion --
 
 fn zval_to_fluent_value(zv: Zval) -> FluentValue<'static> {
     if zv.is_string() {
         FluentValue::String(zv.string().unwrap().into())
-    } else if zv.is_long() || zv.is_double() {
-        FluentValue::Number(zv.double().unwrap().into())
+    } else if zv.is_long() {
+        number::from_integer(zv.long().unwrap())
+    } else if zv.is_double() {
+        number::from_float(zv.double().unwrap())
     } else if zv.is_null() {
         FluentValue::None
     } else if zv.is_bool() {
         FluentValue::Custom(Box::new(FluentPhpBoolValue(zv.bool().unwrap())))
-    } else if zv.is_object() {
-        FluentValue::Custom(Box::new(FluentPhpObjectValue::new(zv.shallow_clone())))
+    } else if zv.is_object() || zv.is_array() {
+        FluentPhpValue::from_zval(&zv).map_or(FluentValue::Error, FluentValue::from)
     } else {
         FluentValue::Error
     }
 }
 
-#[derive(Debug)]
-struct FluentPhpArgs<'a>(FluentArgs<'a>);
+/// Wrap a PHP object or associative array as a Fluent custom value, recognizing
+/// `DateTimeInterface` and arbitrary-precision numbers.
+fn object_to_fluent_value(zv: &Zval) -> FluentValue<'static> {
+    if let Some(datetime) = FluentPhpDateTimeValue::from_zval(zv) {
+        return FluentValue::Custom(Box::new(datetime));
+    }
+    if let Some(number) = php_decimal_string(zv).and_then(|n| number::parse_decimal(&n)) {
+        return number;
+    }
+
+    FluentValue::Custom(Box::new(FluentPhpObjectValue::new(zv.shallow_clone())))
+}
+
+/// The decimal digits of a `GMP` or `BcMath\Number` object; `None` for any
+/// other value.
+fn php_decimal_string(zv: &Zval) -> Option<String> {
+    let object = zv.object()?;
+    if ClassEntry::try_find("GMP").is_some_and(|ce| object.instance_of(ce)) {
+        ZendCallable::try_from_name("gmp_strval")
+            .ok()?
+            .try_call(vec![zv])
+            .ok()?
+            .string()
+    } else if ClassEntry::try_find("BcMath\\Number").is_some_and(|ce| object.instance_of(ce)) {
+        object.try_call_method("__toString", vec![]).ok()?.string()
+    } else {
+        None
+    }
+}
+
+/// The value of an enum case, so enums can drive selectors: the backing value
+/// of a backed enum, or the case name of a pure one. `None` for any other
+/// value.
+fn php_enum_case(zv: &Zval) -> Option<FluentPhpValue> {
+    let object = zv.object()?;
+    if !object.instance_of(ClassEntry::try_find("UnitEnum")?) {
+        return None;
+    }
+
+    if object.instance_of(ClassEntry::try_find("BackedEnum")?) {
+        object.get_property::<FluentPhpValue>("value").ok()
+    } else {
+        object
+            .get_property::<String>("name")
+            .ok()
+            .map(FluentPhpValue::Str)
+    }
+}
+
+/// A Fluent number as a PHP value: integers without fraction digits become
+/// `int`s, unless they were PHP `float`s.
+fn number_to_php(number: &FluentNumber) -> FluentPhpValue {
+    let integral =
+        number.value.fract() == 0.0 && number.value.abs() <= number::MAX_SAFE_INTEGER as f64;
+    match number.options.minimum_fraction_digits {
+        None | Some(0) if integral && !number::is_float(number) => {
+            FluentPhpValue::Long(number.value as i64)
+        }
+        _ => FluentPhpValue::Double(number.value),
+    }
+}
+
+/// An exact decimal as a PHP `int` when it is one, otherwise as a numeric
+/// string, since a `float` would lose its digits.
+fn decimal_to_php(decimal: &number::FluentDecimal) -> FluentPhpValue {
+    let digits = decimal.value.to_string();
+    match digits.parse() {
+        Ok(n) => FluentPhpValue::Long(n),
+        Err(_) => FluentPhpValue::Str(digits),
+    }
+}
+
+#[derive(Debug, Default)]
+struct FluentPhpArgs<'a> {
+    args: FluentArgs<'a>,
+    /// Arguments that are associative arrays, which only `KEY()` may read.
+    arrays: Vec<String>,
+}
 
 impl<'a> Deref for FluentPhpArgs<'a> {
     type Target = FluentArgs<'a>;
 
     fn deref(&self) -> &FluentArgs<'a> {
-        &self.0
+        &self.args
     }
 }
 
@@ -373,25 +833,31 @@ impl<'a> TryFrom<&ZendHashTable> for FluentPhpArgs<'a> {
     type Error = FluentPhpError;
 
     fn try_from(value: &ZendHashTable) -> Result<Self, Self::Error> {
-        let mut args = FluentArgs::new();
+        let mut args = FluentPhpArgs::default();
         for (key, elem) in value.iter() {
             let value = match FluentPhpValue::from_zval(elem) {
                 Some(v) => v,
-                None => {
-                    return Err(FluentPhpError::Message(format!(
-                        "Unsupported type for argument \"{}\": {}.",
-                        key,
-                        elem.get_type()
-                    )));
+                // Checked against the pattern when it is formatted.
+                None if elem.is_array() => {
+                    args.arrays.push(key.to_string());
+                    FluentPhpValue::Zval(elem.shallow_clone())
                 }
+                None => return Err(unsupported_argument(&key.to_string(), elem.get_type())),
             };
-            args.set(key.to_string(), value);
+            args.args.set(key.to_string(), value);
         }
 
-        Ok(FluentPhpArgs(args))
+        Ok(args)
     }
 }
 
+fn unsupported_argument(name: &str, data_type: DataType) -> FluentPhpError {
+    FluentPhpError::Message(format!(
+        "Unsupported type for argument \"{}\": {}.",
+        name, data_type
+    ))
+}
+
 #[derive(Debug)]
 struct ThreadSafeWrapper<T> {
     inner: Mutex<T>,
@@ -417,105 +883,390 @@ impl<T> Deref for ThreadSafeWrapper<T> {
     }
 }
 
-#[derive(Debug)]
-struct ThreadSafeZendCallable(ThreadSafeWrapper<ZendCallable<'static>>);
-
-impl ThreadSafeZendCallable {
-    fn new(callable: ZendCallable<'static>) -> Self {
-        Self(ThreadSafeWrapper::new(callable))
-    }
+/// How Fluent named arguments (`FOO($x, style: "short")`) reach a PHP
+/// function.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum NamedArgs {
+    /// A trailing associative array, appended only when named arguments are
+    /// present.
+    Array,
+    /// PHP 8 named parameters.
+    Named,
+}
 
-    fn lock(&self) -> MutexGuard<'_, ZendCallable<'static>> {
-        self.0.lock()
+impl NamedArgs {
+    fn from_php(mode: &str) -> PhpResult<Self> {
+        if mode.eq_ignore_ascii_case("array") {
+            Ok(Self::Array)
+        } else if mode.eq_ignore_ascii_case("named") {
+            Ok(Self::Named)
+        } else {
+            Err(PhpException::from_class::<Exception>(format!(
+                "Unknown named argument mode \"{}\"; expected \"array\" or \"named\".",
+                mode
+            )))
+        }
     }
 }
 
-// Fluent stores functions behind `Send + Sync` trait objects. PHP callables are
-// only invoked synchronously by the PHP request thread in this extension; the
-// mutex prevents re-entrant mutable access if Fluent calls the function more
-// than once during formatting.
-unsafe impl Send for ThreadSafeZendCallable {}
-unsafe impl Sync for ThreadSafeZendCallable {}
+/// A PHP callable registered as a Fluent function.
+struct PhpFunction {
+    name: String,
+    callable: ThreadSafeWrapper<Zval>,
+    named_args: NamedArgs,
+}
 
-#[derive(Debug)]
-struct FluentPhpObjectValue(ThreadSafeWrapper<Zval>);
+impl PhpFunction {
+    fn new(name: &str, callable: &Zval, named_args: NamedArgs) -> PhpResult<Self> {
+        if !callable.is_callable() {
+            return Err(PhpException::from_class::<Exception>(
+                "addFunction() expects a valid callable.".to_string(),
+            ));
+        }
 
-impl FluentPhpObjectValue {
-    fn new(zv: Zval) -> Self {
-        Self(ThreadSafeWrapper::new(zv))
+        Ok(Self {
+            name: name.to_string(),
+            callable: ThreadSafeWrapper::new(callable.shallow_clone()),
+            named_args,
+        })
     }
 
-    fn lock(&self) -> MutexGuard<'_, Zval> {
-        self.0.lock()
-    }
+    /// Call the function, collecting an exception it throws in `exceptions`.
+    fn call(
+        &self,
+        positional: &[FluentValue],
+        named: &FluentArgs,
+        exceptions: &FunctionExceptions,
+    ) -> FluentValue<'static> {
+        let positional: Result<Vec<FluentPhpValue>, FluentPhpError> =
+            positional.iter().map(|p| p.try_into()).collect();
+        let named: Result<Vec<(&str, FluentPhpValue)>, FluentPhpError> = named
+            .iter()
+            .map(|(key, value)| Ok((key, value.try_into()?)))
+            .collect();
 
-    fn stringify(&self) -> std::borrow::Cow<'static, str> {
-        let zval = self.lock();
-        if let Some(object) = zval.object() {
-            if object.instance_of(ce::stringable()) {
-                let result = object.try_call_method("__toString", vec![]);
-                if let Ok(result) = result {
-                    return result.str().unwrap().to_string().into();
+        let (Ok(positional), Ok(named)) = (positional, named) else {
+            return FluentValue::Error;
+        };
+
+        // Not called under the lock: the callable may format with this bundle.
+        let callable = self.callable.lock().shallow_clone();
+        let result = match self.named_args {
+            NamedArgs::Array => {
+                let options = (!named.is_empty()).then(|| php_array(vec![], named));
+                let mut args: Vec<&dyn IntoZvalDyn> =
+                    positional.iter().map(|p| p as &dyn IntoZvalDyn).collect();
+                if let Some(options) = &options {
+                    args.push(options);
                 }
+
+                ZendCallable::new(&callable).and_then(|callable| callable.try_call(args))
+            }
+            NamedArgs::Named => {
+                // `call_user_func_array()` passes string keys as named parameters.
+                let args = php_array(positional, named);
+                ZendCallable::try_from_name("call_user_func_array")
+                    .and_then(|call| call.try_call(vec![&callable, &args]))
             }
+        };
 
-            return "[Object]".into();
+        match result {
+            Ok(value) => zval_to_fluent_value(value),
+            Err(ext_php_rs::error::Error::Exception(exception)) => {
+                exceptions.push(FunctionException::new(&self.name, exception));
+                FluentValue::Error
+            }
+            Err(_) => FluentValue::Error,
         }
+    }
+}
 
-        "[Object]".into()
+// Fluent stores functions behind `Send + Sync` trait objects. PHP callables are
+// only invoked synchronously by the PHP request thread in this extension; the
+// mutex only guards copying the callable out, so a function may format with
+// the bundle that called it.
+unsafe impl Send for PhpFunction {}
+unsafe impl Sync for PhpFunction {}
+
+/// Exceptions thrown by a bundle's PHP functions, collected while a pattern is
+/// formatted and drained afterwards.
+#[derive(Default)]
+struct FunctionExceptions(Mutex<Vec<FunctionException>>);
+
+impl FunctionExceptions {
+    fn push(&self, exception: FunctionException) {
+        self.0.lock().unwrap().push(exception);
     }
 
-    fn object_identity(&self) -> Option<(usize, u32)> {
-        let zval = self.lock();
-        zval.object()
-            .map(|object| (object.ce as usize, object.handle))
+    fn take(&self) -> Vec<FunctionException> {
+        std::mem::take(&mut *self.0.lock().unwrap())
     }
 }
 
-// Fluent custom values must be `Send`, but PHP objects are raw Zend VM handles.
-// The extension formats messages synchronously inside a PHP request; objects are
-// mutex-protected and never intentionally moved to a Rust worker thread.
-unsafe impl Send for FluentPhpObjectValue {}
-unsafe impl Sync for FluentPhpObjectValue {}
+// Shared between a bundle and its functions; like `PhpFunction`, only used
+// synchronously from the PHP request thread.
+unsafe impl Send for FunctionExceptions {}
+unsafe impl Sync for FunctionExceptions {}
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct FluentPhpBoolValue(bool);
+/// Lookups by the `PROP()` and `KEY()` builtins that found nothing, and calls to
+/// functions since removed from a registry, collected while a pattern is
+/// formatted and reported as resolver errors afterwards.
+#[derive(Default)]
+struct MissingValues(Mutex<Vec<FluentError>>);
 
-impl FluentPhpBoolValue {
-    fn stringify(&self) -> std::borrow::Cow<'static, str> {
-        if self.0 { "true" } else { "false" }.into()
+impl MissingValues {
+    fn push(&self, access: Access, key: &str) {
+        let id = format!("{}(\"{}\")", access.name(), key);
+        self.push_error(ResolverError::NoValue(id));
     }
 
-    fn to_php_value(self) -> FluentPhpValue {
-        FluentPhpValue::Bool(self.0)
+    fn push_unknown_function(&self, name: &str) {
+        let id = name.to_string();
+        self.push_error(ResolverError::Reference(ReferenceKind::Function { id }));
     }
-}
 
-impl PartialEq for FluentPhpObjectValue {
-    fn eq(&self, other: &Self) -> bool {
-        // Required by fluent-bundle's `FluentValue::Custom` equality support.
-        // Current selector resolution does not compare custom object values; use
-        // object identity here to avoid invoking PHP/Zend comparison handlers
-        // from Rust `PartialEq`.
-        self.object_identity()
-            .zip(other.object_identity())
-            .is_some_and(|(left, right)| left == right)
+    fn push_error(&self, error: ResolverError) {
+        self.0
+            .lock()
+            .unwrap()
+            .push(FluentError::ResolverError(error));
     }
-}
 
-impl FluentType for FluentPhpBoolValue {
-    fn duplicate(&self) -> Box<dyn FluentType + Send> {
-        Box::new(*self)
+    fn take(&self) -> Vec<FluentError> {
+        std::mem::take(&mut *self.0.lock().unwrap())
     }
+}
 
-    fn as_string(
-        &self,
-        _intls: &intl_memoizer::IntlLangMemoizer,
-    ) -> std::borrow::Cow<'static, str> {
-        self.stringify()
-    }
+/// The `PROP()` and `KEY()` builtins, which read into object and array
+/// arguments natively instead of through a PHP callback.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Access {
+    /// `PROP($obj, "name")`: a public property.
+    Property,
+    /// `KEY($arr, "key")`: a key of an array, a list or an `ArrayAccess`
+    /// object.
+    Key,
+}
 
-    fn as_string_threadsafe(
+impl Access {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Property => "PROP",
+            Self::Key => "KEY",
+        }
+    }
+
+    /// Read the value named by the second argument from the first. A missing
+    /// value is `None` and recorded in `missing`; a missing argument is `None`
+    /// too, so chained lookups report only the first miss.
+    fn call(self, positional: &[FluentValue], missing: &MissingValues) -> FluentValue<'static> {
+        let (Some(value), Some(key)) = (positional.first(), positional.get(1).and_then(access_key))
+        else {
+            return FluentValue::Error;
+        };
+
+        match value {
+            FluentValue::None => FluentValue::None,
+            FluentValue::Error => FluentValue::Error,
+            _ => self.read(value, &key).unwrap_or_else(|| {
+                missing.push(self, &key);
+                FluentValue::None
+            }),
+        }
+    }
+
+    fn read(self, value: &FluentValue, key: &str) -> Option<FluentValue<'static>> {
+        let FluentValue::Custom(value) = value else {
+            return None;
+        };
+        if let Some(list) = value.as_ref().as_any().downcast_ref::<list::FluentList>() {
+            return match self {
+                Self::Property => None,
+                Self::Key => list.items.get(key.parse::<usize>().ok()?).cloned(),
+            };
+        }
+
+        let object = value
+            .as_ref()
+            .as_any()
+            .downcast_ref::<FluentPhpObjectValue>()?;
+        let found = {
+            let zval = object.lock();
+            match self {
+                Self::Property => php_property(zval.object()?, key),
+                Self::Key => php_key(&zval, key),
+            }
+        }?;
+        Some(match FluentPhpValue::from_zval(&found) {
+            Some(value) => value.into(),
+            // An associative array, kept for further `KEY()` lookups.
+            None if found.is_array() => object_to_fluent_value(&found),
+            None => FluentValue::Error,
+        })
+    }
+}
+
+/// A `PROP()` or `KEY()` name: a string, or an integer such as a list index.
+fn access_key(key: &FluentValue) -> Option<String> {
+    match key {
+        FluentValue::String(key) => Some(key.to_string()),
+        FluentValue::Number(n) if n.value.fract() == 0.0 => Some((n.value as i64).to_string()),
+        _ => None,
+    }
+}
+
+/// A public property of `object`, read from its property table so that
+/// `__get()` is never called. Private and protected properties are stored
+/// under mangled names, so they are not found.
+fn php_property(object: &ZendObject, name: &str) -> Option<Zval> {
+    let properties = object.get_properties().ok()?;
+    let value = properties.get(name)?;
+    // Declared properties are stored indirectly, in the object itself.
+    let value = value.indirect().unwrap_or(value);
+    (value.get_type() != DataType::Undef).then(|| value.shallow_clone())
+}
+
+/// An element of an array, or of an `ArrayAccess` object through
+/// `offsetExists()` and `offsetGet()`.
+fn php_key(zv: &Zval, key: &str) -> Option<Zval> {
+    if let Some(array) = zv.array() {
+        return array
+            .iter()
+            .find(|(k, _)| k.to_string() == key)
+            .map(|(_, value)| value.shallow_clone());
+    }
+
+    let object = zv.object()?;
+    if !object.instance_of(ClassEntry::try_find("ArrayAccess")?) {
+        return None;
+    }
+    let exists = object.try_call_method("offsetExists", vec![&key]).ok()?;
+    if !exists.bool().unwrap_or(false) {
+        return None;
+    }
+    object.try_call_method("offsetGet", vec![&key]).ok()
+}
+
+/// Build a PHP array of `positional` values followed by `named` ones.
+fn php_array(positional: Vec<FluentPhpValue>, named: Vec<(&str, FluentPhpValue)>) -> Zval {
+    let mut ht = ZendHashTable::new();
+    for value in positional {
+        ht.push(value).unwrap();
+    }
+    for (key, value) in named {
+        ht.insert(key, value).unwrap();
+    }
+
+    let mut zv = Zval::new();
+    zv.set_hashtable(ht);
+    zv
+}
+
+#[derive(Debug)]
+struct FluentPhpObjectValue(ThreadSafeWrapper<Zval>);
+
+impl FluentPhpObjectValue {
+    fn new(zv: Zval) -> Self {
+        Self(ThreadSafeWrapper::new(zv))
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Zval> {
+        self.0.lock()
+    }
+
+    /// Format through `toFluentString()` for `FluentPhp\FluentValue` objects
+    /// when the locale is known, otherwise through `__toString()`.
+    fn stringify(&self, locale: Option<&str>) -> std::borrow::Cow<'static, str> {
+        let zval = self.lock();
+        if let Some(object) = zval.object() {
+            if let Some(locale) = locale.filter(|_| is_fluent_value(object)) {
+                let result = object.try_call_method("toFluentString", vec![&locale]);
+                if let Some(result) = result.ok().and_then(|result| result.string()) {
+                    return result.into();
+                }
+            }
+
+            if object.instance_of(ce::stringable()) {
+                let result = object.try_call_method("__toString", vec![]);
+                if let Ok(result) = result {
+                    return result.str().unwrap().to_string().into();
+                }
+            }
+
+            return "[Object]".into();
+        }
+
+        if zval.is_array() {
+            return "[Array]".into();
+        }
+
+        "[Object]".into()
+    }
+
+    /// The `toFluentSelector()` result of a `FluentPhp\FluentValue` object;
+    /// `None` for other objects or a `null` selector.
+    fn selector(&self) -> Option<FluentValue<'static>> {
+        let zval = self.lock();
+        let object = zval.object()?;
+        if !is_fluent_value(object) {
+            return None;
+        }
+
+        let selector = object.try_call_method("toFluentSelector", vec![]).ok()?;
+        (!selector.is_null()).then(|| zval_to_fluent_value(selector))
+    }
+
+    fn object_identity(&self) -> Option<(usize, u32)> {
+        let zval = self.lock();
+        zval.object()
+            .map(|object| (object.ce as usize, object.handle))
+    }
+}
+
+// Fluent custom values must be `Send`, but PHP objects are raw Zend VM handles.
+// The extension formats messages synchronously inside a PHP request; objects are
+// mutex-protected and never intentionally moved to a Rust worker thread.
+unsafe impl Send for FluentPhpObjectValue {}
+unsafe impl Sync for FluentPhpObjectValue {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FluentPhpBoolValue(bool);
+
+impl FluentPhpBoolValue {
+    fn stringify(&self) -> std::borrow::Cow<'static, str> {
+        if self.0 { "true" } else { "false" }.into()
+    }
+
+    fn to_php_value(self) -> FluentPhpValue {
+        FluentPhpValue::Bool(self.0)
+    }
+}
+
+impl PartialEq for FluentPhpObjectValue {
+    fn eq(&self, other: &Self) -> bool {
+        // Required by fluent-bundle's `FluentValue::Custom` equality support.
+        // Current selector resolution does not compare custom object values; use
+        // object identity here to avoid invoking PHP/Zend comparison handlers
+        // from Rust `PartialEq`.
+        self.object_identity()
+            .zip(other.object_identity())
+            .is_some_and(|(left, right)| left == right)
+    }
+}
+
+impl FluentType for FluentPhpBoolValue {
+    fn duplicate(&self) -> Box<dyn FluentType + Send> {
+        Box::new(*self)
+    }
+
+    fn as_string(
+        &self,
+        _intls: &intl_memoizer::IntlLangMemoizer,
+    ) -> std::borrow::Cow<'static, str> {
+        self.stringify()
+    }
+
+    fn as_string_threadsafe(
         &self,
         _intls: &intl_memoizer::concurrent::IntlLangMemoizer,
     ) -> std::borrow::Cow<'static, str> {
@@ -528,271 +1279,1678 @@ impl FluentType for FluentPhpObjectValue {
         Box::new(FluentPhpObjectValue::new(self.lock().shallow_clone()))
     }
 
-    fn as_string(
+    fn as_string(&self, intls: &intl_memoizer::IntlLangMemoizer) -> std::borrow::Cow<'static, str> {
+        self.stringify(Some(locale::memoizer_tag(intls).as_str()))
+    }
+
+    fn as_string_threadsafe(
         &self,
-        _intls: &intl_memoizer::IntlLangMemoizer,
+        _intls: &intl_memoizer::concurrent::IntlLangMemoizer,
+    ) -> std::borrow::Cow<'static, str> {
+        // Bundles in this extension are not concurrent.
+        self.stringify(None)
+    }
+}
+
+/// A PHP `DateTimeInterface`, formatted with CLDR data. The original object is
+/// kept so PHP functions receive it unchanged.
+#[derive(Debug)]
+struct FluentPhpDateTimeValue {
+    datetime: datetime::FluentDateTime,
+    zval: ThreadSafeWrapper<Zval>,
+}
+
+impl FluentPhpDateTimeValue {
+    /// Read a `DateTimeInterface`; `None` for any other value.
+    fn from_zval(zv: &Zval) -> Option<Self> {
+        let object = zv.object()?;
+        if !object.instance_of(ClassEntry::try_find("DateTimeInterface")?) {
+            return None;
+        }
+
+        let timestamp = object
+            .try_call_method("getTimestamp", vec![])
+            .ok()?
+            .long()?;
+        let offset = object.try_call_method("getOffset", vec![]).ok()?.long()?;
+
+        Some(Self {
+            datetime: datetime::FluentDateTime::new(timestamp, i32::try_from(offset).ok()?),
+            zval: ThreadSafeWrapper::new(zv.shallow_clone()),
+        })
+    }
+
+    fn with_datetime(&self, datetime: datetime::FluentDateTime) -> Self {
+        Self {
+            datetime,
+            zval: ThreadSafeWrapper::new(self.lock().shallow_clone()),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Zval> {
+        self.zval.lock()
+    }
+
+    /// ISO 8601 output from PHP, for instants CLDR formatting cannot handle.
+    fn stringify(&self) -> std::borrow::Cow<'static, str> {
+        let zval = self.lock();
+        zval.object()
+            .and_then(|object| {
+                object
+                    .try_call_method("format", vec![&"Y-m-d\\TH:i:sP"])
+                    .ok()
+            })
+            .and_then(|result| result.string())
+            .unwrap_or_default()
+            .into()
+    }
+}
+
+// Like `FluentPhpObjectValue`, only used synchronously from the PHP request
+// thread.
+unsafe impl Send for FluentPhpDateTimeValue {}
+unsafe impl Sync for FluentPhpDateTimeValue {}
+
+impl PartialEq for FluentPhpDateTimeValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.datetime == other.datetime
+    }
+}
+
+impl FluentType for FluentPhpDateTimeValue {
+    fn duplicate(&self) -> Box<dyn FluentType + Send> {
+        Box::new(self.with_datetime(self.datetime.clone()))
+    }
+
+    fn as_string(&self, intls: &intl_memoizer::IntlLangMemoizer) -> std::borrow::Cow<'static, str> {
+        match self.datetime.format(intls) {
+            Some(formatted) => formatted.into(),
+            None => self.stringify(),
+        }
+    }
+
+    fn as_string_threadsafe(
+        &self,
+        _intls: &intl_memoizer::concurrent::IntlLangMemoizer,
     ) -> std::borrow::Cow<'static, str> {
+        // Bundles in this extension are not concurrent.
         self.stringify()
     }
+}
+
+/// The `DATETIME()` builtin: apply `dateStyle`, `timeStyle` and `timeZone` to a
+/// `DateTimeInterface` argument.
+fn datetime_builtin<'a>(positional: &[FluentValue<'a>], named: &FluentArgs) -> FluentValue<'a> {
+    let Some(FluentValue::Custom(value)) = positional.first() else {
+        return FluentValue::Error;
+    };
+    let Some(value) = value
+        .as_ref()
+        .as_any()
+        .downcast_ref::<FluentPhpDateTimeValue>()
+    else {
+        return FluentValue::Error;
+    };
+
+    let mut datetime = value.datetime.clone();
+    datetime.options.merge(named);
+    if let Some(FluentValue::String(time_zone)) = named.get("timeZone") {
+        let Some(offset) = php_utc_offset(time_zone, datetime.timestamp) else {
+            return FluentValue::Error;
+        };
+        datetime.offset = offset;
+    }
+
+    FluentValue::Custom(Box::new(value.with_datetime(datetime)))
+}
+
+/// The UTC offset of `time_zone` (an identifier such as `Europe/Paris`, or an
+/// offset such as `+02:00`) at `timestamp`, from PHP's time zone database.
+fn php_utc_offset(time_zone: &str, timestamp: i64) -> Option<i32> {
+    let zone = ZendCallable::try_from_name("timezone_open")
+        .ok()?
+        .try_call(vec![&time_zone])
+        .ok()?;
+    if !zone.is_object() {
+        return None;
+    }
+
+    let instant = ZendCallable::try_from_name("date_create")
+        .ok()?
+        .try_call(vec![&format!("@{}", timestamp)])
+        .ok()?;
+    let offset = ZendCallable::try_from_name("timezone_offset_get")
+        .ok()?
+        .try_call(vec![&zone, &instant])
+        .ok()?;
+
+    i32::try_from(offset.long()?).ok()
+}
+
+enum FluentPhpValue {
+    Bool(bool),
+    Double(f64),
+    Long(i64),
+    Str(String),
+    /// An indexed array.
+    List(Vec<FluentPhpValue>),
+    Zval(Zval),
+    None,
+}
+
+impl Display for FluentPhpValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Str(s) => write!(f, "{}", &s),
+            Self::Bool(true) => write!(f, "true"),
+            Self::Bool(false) => write!(f, "false"),
+            Self::Long(n) => write!(f, "{}", n),
+            Self::Double(fl) => write!(f, "{}", fl),
+            Self::None => write!(f, ""),
+            Self::List(items) => {
+                let items: Vec<String> = items.iter().map(ToString::to_string).collect();
+                write!(f, "{}", items.join(", "))
+            }
+            Self::Zval(zv) => match zv {
+                val if val.is_long() => write!(f, "{}", val.long().unwrap()),
+                val if val.is_double() => write!(f, "{}", val.double().unwrap()),
+                val if val.is_string() => write!(f, "{}", val.str().unwrap()),
+                _ => write!(f, "{}", zv.str().unwrap_or("")),
+            },
+        }
+    }
+}
+
+impl Clone for FluentPhpValue {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Str(val) => Self::Str(val.clone()),
+            Self::Bool(val) => Self::Bool(*val),
+            Self::Long(val) => Self::Long(*val),
+            Self::Double(val) => Self::Double(*val),
+            Self::None => Self::None,
+            Self::List(val) => Self::List(val.clone()),
+            Self::Zval(val) => Self::Zval(val.shallow_clone()),
+        }
+    }
+}
+
+impl TryFrom<&FluentValue<'_>> for FluentPhpValue {
+    type Error = FluentPhpError;
+
+    fn try_from(value: &FluentValue<'_>) -> Result<Self, FluentPhpError> {
+        let value = match value {
+            FluentValue::String(s) => Self::Str(s.clone().into()),
+            FluentValue::Number(n) => number_to_php(n),
+            FluentValue::None => Self::None,
+            FluentValue::Custom(val) => {
+                if let Some(val) = val.as_ref().as_any().downcast_ref::<FluentPhpObjectValue>() {
+                    FluentPhpValue::Zval(val.lock().shallow_clone())
+                } else if let Some(val) = val.as_ref().as_any().downcast_ref::<FluentPhpBoolValue>()
+                {
+                    val.to_php_value()
+                } else if let Some(val) = val
+                    .as_ref()
+                    .as_any()
+                    .downcast_ref::<FluentPhpDateTimeValue>()
+                {
+                    FluentPhpValue::Zval(val.lock().shallow_clone())
+                } else if let Some(val) = val
+                    .as_ref()
+                    .as_any()
+                    .downcast_ref::<number::FluentDecimal>()
+                {
+                    decimal_to_php(val)
+                } else if let Some(val) = val.as_ref().as_any().downcast_ref::<list::FluentList>() {
+                    let items: Result<Vec<FluentPhpValue>, FluentPhpError> =
+                        val.items.iter().map(|item| item.try_into()).collect();
+                    FluentPhpValue::List(items?)
+                } else {
+                    FluentPhpValue::None
+                }
+            }
+            FluentValue::Error => {
+                return Err(FluentPhpError::Message(
+                    "Unsupported variable type".to_string(),
+                ));
+            }
+        };
+
+        Ok(value)
+    }
+}
+
+impl From<FluentPhpValue> for FluentValue<'static> {
+    fn from(value: FluentPhpValue) -> Self {
+        match value {
+            FluentPhpValue::Str(val) => Self::String(val.into()),
+            FluentPhpValue::Long(val) => number::from_integer(val),
+            FluentPhpValue::Double(val) => number::from_float(val),
+            FluentPhpValue::Bool(val) => Self::Custom(Box::new(FluentPhpBoolValue(val))),
+            FluentPhpValue::List(val) => {
+                let items = val.into_iter().map(FluentValue::from).collect();
+                Self::Custom(Box::new(list::FluentList::new(items)))
+            }
+            FluentPhpValue::Zval(val) => object_to_fluent_value(&val),
+            FluentPhpValue::None => Self::None,
+        }
+    }
+}
+
+impl FromZval<'_> for FluentPhpValue {
+    const TYPE: DataType = DataType::Mixed;
+    fn from_zval(zv: &Zval) -> Option<Self> {
+        let val = if zv.is_string() {
+            FluentPhpValue::Str(zv.string().unwrap())
+        } else if zv.is_long() {
+            FluentPhpValue::Long(zv.long().unwrap())
+        } else if zv.is_double() {
+            FluentPhpValue::Double(zv.double().unwrap())
+        } else if zv.is_null() {
+            FluentPhpValue::None
+        } else if zv.is_bool() {
+            FluentPhpValue::Bool(zv.bool().unwrap())
+        } else if let Some(case) = php_enum_case(zv) {
+            case
+        } else if zv.is_object() {
+            FluentPhpValue::Zval(zv.shallow_clone())
+        } else if let Some(array) = zv.array().filter(|array| array.has_sequential_keys()) {
+            let items: Option<Vec<FluentPhpValue>> = array
+                .iter()
+                .map(|(_, item)| FluentPhpValue::from_zval(item))
+                .collect();
+            FluentPhpValue::List(items?)
+        } else {
+            return None;
+        };
+
+        Some(val)
+    }
+}
+
+impl IntoZval for FluentPhpValue {
+    const TYPE: DataType = DataType::Mixed;
+    const NULLABLE: bool = false;
+
+    fn set_zval(self, zv: &mut Zval, persistent: bool) -> ext_php_rs::error::Result<()> {
+        match self {
+            Self::Str(val) => zv.set_string(&val, persistent)?,
+            Self::Bool(val) => zv.set_bool(val),
+            Self::Long(val) => zv.set_long(val),
+            Self::Double(val) => zv.set_double(val),
+            Self::List(val) => {
+                let mut ht = ZendHashTable::new();
+                for item in val {
+                    ht.push(item)?;
+                }
+                zv.set_hashtable(ht);
+            }
+            Self::Zval(val) => *zv = val,
+            Self::None => zv.set_null(),
+        };
+        Ok(())
+    }
+}
+
+// -- FluentValue PHP interface --
+
+/// Implemented by PHP objects that format themselves and choose their own
+/// variant in select expressions.
+#[php_interface]
+#[php(name = "FluentPhp\\FluentValue")]
+trait PhpFluentValue {
+    /// The text shown for the object in `locale`, a BCP-47 tag.
+    fn to_fluent_string(&self, locale: String) -> String;
+
+    /// The string or number select expressions match variant keys against,
+    /// or `null` to use the default variant.
+    fn to_fluent_selector(&self) -> Zval;
+}
+
+fn is_fluent_value(object: &ZendObject) -> bool {
+    ClassEntry::try_find("FluentPhp\\FluentValue").is_some_and(|ce| object.instance_of(ce))
+}
+
+/// Appended to a variable name to name the argument holding its selector.
+/// `#` cannot appear in FTL identifiers, so it never clashes with a variable.
+const SELECTOR_SUFFIX: &str = "#selector";
+
+/// What select expressions on `value` match against, for values Fluent does
+/// not select on itself: the `toFluentSelector()` result of `FluentValue`
+/// objects, the item count of lists, and the plural category of exact
+/// decimals, taken from their digits rather than an `f64`.
+fn selector_value(value: &FluentValue, intls: &IntlLangMemoizer) -> Option<FluentValue<'static>> {
+    let FluentValue::Custom(custom) = value else {
+        return None;
+    };
+    let custom = custom.as_ref().as_any();
+    if let Some(object) = custom.downcast_ref::<FluentPhpObjectValue>() {
+        let selector = object.selector()?;
+        Some(selector_value(&selector, intls).unwrap_or(selector))
+    } else if let Some(list) = custom.downcast_ref::<list::FluentList>() {
+        Some(FluentValue::Number(list.count()))
+    } else if let Some(decimal) = custom.downcast_ref::<number::FluentDecimal>() {
+        decimal.plural_category(intls).map(FluentValue::from)
+    } else {
+        None
+    }
+}
+
+// -- FunctionRegistry PHP class --
+
+/// Functions every bundle has, which a registry cannot replace.
+const CORE_FUNCTIONS: [&str; 5] = ["NUMBER", "DATETIME", "LIST", "PROP", "KEY"];
+
+/// A function held by a `FunctionRegistry`.
+#[derive(Clone)]
+enum RegisteredFunction {
+    Php(Arc<PhpFunction>),
+    /// A function from a `builtins` group, called by name.
+    Builtin,
+}
+
+/// The functions of a registry, shared with every bundle it is attached to.
+#[derive(Default)]
+struct RegistryFunctions(Mutex<BTreeMap<String, RegisteredFunction>>);
+
+impl RegistryFunctions {
+    fn get(&self, name: &str) -> Option<RegisteredFunction> {
+        self.0.lock().unwrap().get(name).cloned()
+    }
+
+    fn insert(&self, name: String, function: RegisteredFunction) {
+        self.0.lock().unwrap().insert(name, function);
+    }
+
+    fn remove(&self, name: &str) -> bool {
+        self.0.lock().unwrap().remove(name).is_some()
+    }
+
+    fn names(&self) -> Vec<String> {
+        self.0.lock().unwrap().keys().cloned().collect()
+    }
+}
+
+/// Calls a registry's current function for one name, so functions replaced
+/// or removed after a bundle was attached behave accordingly in that bundle.
+struct RegistryDispatcher {
+    name: String,
+    functions: Arc<RegistryFunctions>,
+    /// The bundle's locale, for locale-aware builtins. A bundle's locale
+    /// never changes, so it is taken once, as `addBuiltins()` does.
+    lang: icu_locid::LanguageIdentifier,
+    exceptions: Arc<FunctionExceptions>,
+    missing_values: Arc<MissingValues>,
+}
+
+impl RegistryDispatcher {
+    fn call<'a>(&self, positional: &[FluentValue<'a>], named: &FluentArgs) -> FluentValue<'a> {
+        // Not called under the registry lock: the PHP function may use the
+        // registry.
+        match self.functions.get(&self.name) {
+            Some(RegisteredFunction::Php(function)) => {
+                function.call(positional, named, &self.exceptions)
+            }
+            Some(RegisteredFunction::Builtin) => builtins::call(&self.name, &self.lang, positional),
+            None => {
+                self.missing_values.push_unknown_function(&self.name);
+                FluentValue::Error
+            }
+        }
+    }
+}
+
+#[php_class]
+#[php(name = "FluentPhp\\FunctionRegistry")]
+#[derive(Default)]
+struct FunctionRegistry {
+    functions: Arc<RegistryFunctions>,
+}
+
+#[php_impl]
+impl FunctionRegistry {
+    fn __construct() -> Self {
+        Self::default()
+    }
+
+    /// Add a PHP function, replacing any function of the same name.
+    #[php(optional = named_args)]
+    pub fn add_function(
+        &self,
+        fn_name: String,
+        callable: &Zval,
+        named_args: Option<String>,
+    ) -> PhpResult<()> {
+        Self::check_name(&fn_name)?;
+        let named_args = match named_args {
+            Some(mode) => NamedArgs::from_php(&mode)?,
+            None => NamedArgs::Array,
+        };
+        let function = PhpFunction::new(&fn_name, callable, named_args)?;
+
+        self.functions
+            .insert(fn_name, RegisteredFunction::Php(Arc::new(function)));
+        Ok(())
+    }
+
+    /// Add groups of native functions, replacing any functions of the same
+    /// names.
+    pub fn add_builtins(&self, groups: Vec<String>) -> PhpResult<()> {
+        for group in builtin_groups(&groups)? {
+            for name in group.names() {
+                self.functions
+                    .insert(name.to_string(), RegisteredFunction::Builtin);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn remove_function(&self, fn_name: String) -> bool {
+        self.functions.remove(&fn_name)
+    }
+
+    pub fn has_function(&self, fn_name: String) -> bool {
+        self.functions.get(&fn_name).is_some()
+    }
+
+    /// The registered names, in alphabetical order.
+    pub fn get_function_names(&self) -> Vec<String> {
+        self.functions.names()
+    }
+}
+
+impl FunctionRegistry {
+    fn check_name(name: &str) -> PhpResult<()> {
+        if CORE_FUNCTIONS.contains(&name) {
+            return Err(PhpException::from_class::<Exception>(format!(
+                "Attempt to override an existing function: \"{}\".",
+                name
+            )));
+        }
+        Ok(())
+    }
+
+    /// The functions of a `FunctionRegistry` object.
+    fn shared_functions(registry: &Zval) -> PhpResult<Arc<RegistryFunctions>> {
+        registry
+            .object()
+            .and_then(|obj| obj.extract::<&FunctionRegistry>().ok())
+            .map(|registry| Arc::clone(&registry.functions))
+            .ok_or_else(|| {
+                PhpException::from_class::<Exception>(
+                    "Expected a FunctionRegistry instance.".to_string(),
+                )
+            })
+    }
+}
+
+// -- Transform and formatter hooks --
+
+/// A PHP callable that rewrites the literal text of patterns, set with
+/// `setTransform()`.
+struct PhpTransform {
+    callable: ThreadSafeWrapper<Zval>,
+    /// Results by text, when the callable is declared pure.
+    cache: Option<Mutex<HashMap<String, String>>>,
+}
+
+impl PhpTransform {
+    fn new(callable: &Zval, pure: bool) -> PhpResult<Self> {
+        if !callable.is_callable() {
+            return Err(PhpException::from_class::<Exception>(
+                "setTransform() expects a valid callable.".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            callable: ThreadSafeWrapper::new(callable.shallow_clone()),
+            cache: pure.then(Mutex::default),
+        })
+    }
+
+    /// Transform `text`, collecting an exception the callable throws in
+    /// `exceptions`. The text is kept when the callable fails or does not
+    /// return a string.
+    fn call(&self, text: &str, exceptions: &FunctionExceptions) -> String {
+        if let Some(cached) = self
+            .cache
+            .as_ref()
+            .and_then(|cache| cache.lock().unwrap().get(text).cloned())
+        {
+            return cached;
+        }
+
+        // Not called under the lock: the callable may format with this bundle.
+        let callable = self.callable.lock().shallow_clone();
+        let result =
+            ZendCallable::new(&callable).and_then(|callable| callable.try_call(vec![&text]));
+        let transformed = match result {
+            Ok(value) => match value.string() {
+                Some(transformed) => transformed,
+                None => return text.to_string(),
+            },
+            Err(ext_php_rs::error::Error::Exception(exception)) => {
+                exceptions.push(FunctionException::new("transform", exception));
+                return text.to_string();
+            }
+            Err(_) => return text.to_string(),
+        };
+
+        if let Some(cache) = &self.cache {
+            cache
+                .lock()
+                .unwrap()
+                .insert(text.to_string(), transformed.clone());
+        }
+        transformed
+    }
+}
+
+// Like `PhpFunction`, only called synchronously from the PHP request thread.
+unsafe impl Send for PhpTransform {}
+unsafe impl Sync for PhpTransform {}
+
+/// A PHP callable that formats the values placeables show, set with
+/// `setFormatter()`.
+struct PhpFormatter {
+    callable: ThreadSafeWrapper<Zval>,
+}
+
+impl PhpFormatter {
+    fn new(callable: &Zval) -> PhpResult<Self> {
+        if !callable.is_callable() {
+            return Err(PhpException::from_class::<Exception>(
+                "setFormatter() expects a valid callable.".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            callable: ThreadSafeWrapper::new(callable.shallow_clone()),
+        })
+    }
+
+    /// Format `value` for `locale`, collecting an exception the callable throws
+    /// in `exceptions`. `None` falls back to the default formatting.
+    fn call(
+        &self,
+        value: &FluentValue,
+        locale: &str,
+        exceptions: &FunctionExceptions,
+    ) -> Option<String> {
+        let type_name = formatter_type(value)?;
+        let value = FluentPhpValue::try_from(value).ok()?;
+
+        // Not called under the lock: the callable may format with this bundle.
+        let callable = self.callable.lock().shallow_clone();
+        let result = ZendCallable::new(&callable)
+            .and_then(|callable| callable.try_call(vec![&value, &type_name, &locale]));
+        match result {
+            Ok(formatted) => formatted.string(),
+            Err(ext_php_rs::error::Error::Exception(exception)) => {
+                exceptions.push(FunctionException::new("formatter", exception));
+                None
+            }
+            Err(_) => None,
+        }
+    }
+}
+
+// Like `PhpFunction`, only called synchronously from the PHP request thread.
+unsafe impl Send for PhpFormatter {}
+unsafe impl Sync for PhpFormatter {}
+
+/// The type `setFormatter()` callables receive with `value`. Strings are not
+/// passed to them: fluent-bundle also formats each finished pattern as one.
+fn formatter_type(value: &FluentValue) -> Option<&'static str> {
+    match value {
+        FluentValue::Number(_) => Some("number"),
+        FluentValue::Custom(custom) => {
+            let custom = custom.as_ref().as_any();
+            if custom.is::<number::FluentDecimal>() {
+                Some("number")
+            } else if custom.is::<FluentPhpDateTimeValue>() {
+                Some("datetime")
+            } else if custom.is::<list::FluentList>() {
+                Some("list")
+            } else if custom.is::<FluentPhpBoolValue>() {
+                Some("bool")
+            } else if custom.is::<FluentPhpObjectValue>() {
+                Some("object")
+            } else {
+                None
+            }
+        }
+        FluentValue::String(_) | FluentValue::None | FluentValue::Error => None,
+    }
+}
+
+/// The PHP hooks of the bundle formatting on this thread.
+#[derive(Clone)]
+struct ActiveHooks {
+    transform: Option<Arc<PhpTransform>>,
+    formatter: Option<Arc<PhpFormatter>>,
+    pseudo_localization: Option<pseudo::Strategy>,
+    exceptions: Arc<FunctionExceptions>,
+}
+
+thread_local! {
+    /// fluent-bundle takes plain `fn`s as its transform and formatter, so
+    /// `apply_transform()` and `apply_formatter()` find the PHP hooks of the
+    /// formatting bundle here.
+    static ACTIVE_HOOKS: RefCell<Option<ActiveHooks>> = const { RefCell::new(None) };
+}
+
+fn active_hooks() -> Option<ActiveHooks> {
+    ACTIVE_HOOKS.with(|active| active.borrow().clone())
+}
+
+/// The bundle transform while a PHP transform is set: the PHP callable, then
+/// any pseudo-localization.
+fn apply_transform(text: &str) -> Cow<'_, str> {
+    let Some(active) = active_hooks() else {
+        return Cow::Borrowed(text);
+    };
+    let Some(transform) = &active.transform else {
+        return Cow::Borrowed(text);
+    };
+
+    let transformed = transform.call(text, &active.exceptions);
+    match active.pseudo_localization {
+        Some(strategy) => Cow::Owned(strategy.transform()(&transformed).into_owned()),
+        None => Cow::Owned(transformed),
+    }
+}
+
+/// The bundle formatter: the PHP formatter, when one is set and formats the
+/// value, then locale-aware numbers.
+fn apply_formatter(value: &FluentValue, intls: &IntlLangMemoizer) -> Option<String> {
+    active_hooks()
+        .and_then(|active| {
+            let formatter = active.formatter.as_ref()?;
+            formatter.call(value, &locale::memoizer_tag(intls), &active.exceptions)
+        })
+        .or_else(|| number::format_value(value, intls))
+}
+
+/// Makes a bundle's hooks active until dropped, then restores those of the
+/// bundle formatting before it, if any.
+struct HookScope(Option<ActiveHooks>);
+
+impl HookScope {
+    fn enter(active: ActiveHooks) -> Self {
+        Self(ACTIVE_HOOKS.with(|current| current.replace(Some(active))))
+    }
+}
+
+impl Drop for HookScope {
+    fn drop(&mut self) {
+        ACTIVE_HOOKS.with(|current| *current.borrow_mut() = self.0.take());
+    }
+}
+
+// -- FluentBundle PHP class --
+
+/// Settings a bundle can be created with, from the `FluentBundle` constructor
+/// options or the ini defaults.
+#[derive(Clone, Copy, Debug)]
+struct BundleOptions {
+    /// Wrap placeables in Unicode isolation marks (FSI/PDI).
+    use_isolating: bool,
+    pseudo_localization: Option<pseudo::Strategy>,
+    /// Only set from the ini; bundles change it with `setErrorPolicy()`.
+    error_policy: ErrorPolicy,
+}
+
+impl BundleOptions {
+    /// The `fluent.use_isolating`, `fluent.pseudo_localization` and
+    /// `fluent.error_policy` ini settings, read in one pass. Isolation and
+    /// pseudo-localization are off unless set, and unknown values mean off
+    /// and `throw`.
+    fn from_ini() -> Self {
+        let ini = ExecutorGlobals::get().ini_values();
+        let setting = |name: &str| ini.get(name).and_then(|o| o.as_deref());
+
+        Self {
+            use_isolating: setting("fluent.use_isolating").is_some_and(parse_ini_bool),
+            pseudo_localization: setting("fluent.pseudo_localization")
+                .and_then(pseudo::Strategy::parse),
+            error_policy: setting("fluent.error_policy")
+                .and_then(ErrorPolicy::parse)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// The ini defaults, overridden by the constructor's `$options` array.
+    fn from_php(options: Option<&ZendHashTable>) -> PhpResult<Self> {
+        let mut parsed = Self::from_ini();
+        let Some(options) = options else {
+            return Ok(parsed);
+        };
+
+        for (key, value) in options.iter() {
+            match key.to_string().as_str() {
+                "useIsolating" => {
+                    parsed.use_isolating = value.bool().ok_or_else(|| {
+                        PhpException::from_class::<Exception>(
+                            "The \"useIsolating\" option must be a boolean.".to_string(),
+                        )
+                    })?;
+                }
+                "pseudoLocalization" => {
+                    parsed.pseudo_localization = if value.is_null() {
+                        None
+                    } else {
+                        let strategy = value.str().ok_or_else(|| {
+                            PhpException::from_class::<Exception>(
+                                "The \"pseudoLocalization\" option must be a string or null."
+                                    .to_string(),
+                            )
+                        })?;
+                        Some(pseudo_strategy(strategy)?)
+                    };
+                }
+                other => {
+                    return Err(PhpException::from_class::<Exception>(format!(
+                        "Unknown bundle option \"{}\"; expected \"useIsolating\" or \"pseudoLocalization\".",
+                        other
+                    )));
+                }
+            }
+        }
+        Ok(parsed)
+    }
+
+    fn apply(self, bundle: &mut FluentPhpBundle) {
+        bundle.bundle.set_use_isolating(self.use_isolating);
+        bundle.pseudo_localization = self.pseudo_localization;
+        bundle.error_policy = self.error_policy;
+        bundle.update_transform();
+    }
+}
+
+fn pseudo_strategy(strategy: &str) -> PhpResult<pseudo::Strategy> {
+    pseudo::Strategy::parse(strategy).ok_or_else(|| {
+        PhpException::from_class::<Exception>(format!(
+            "Unknown pseudo-localization strategy \"{}\"; expected \"accented\" or \"bidi\".",
+            strategy
+        ))
+    })
+}
+
+#[php_class]
+#[php(name = "FluentPhp\\FluentBundle")]
+struct FluentPhpBundle {
+    bundle: FluentBundle<Arc<FluentResource>>,
+    error_policy: ErrorPolicy,
+    function_exceptions: Arc<FunctionExceptions>,
+    function_exception_mode: FunctionExceptionMode,
+    missing_values: Arc<MissingValues>,
+    registry: Option<Arc<RegistryFunctions>>,
+    /// Registry functions the bundle has a dispatcher for, or already had a
+    /// function of its own for.
+    registry_names: HashSet<String>,
+    pseudo_localization: Option<pseudo::Strategy>,
+    transform: Option<Arc<PhpTransform>>,
+    formatter: Option<Arc<PhpFormatter>>,
+    /// The resources added to the bundle, in order, with their comments, for
+    /// introspection.
+    resources: Vec<(Arc<FluentResource>, introspect::Comments)>,
+    /// Formatters for queries made from PHP. fluent-bundle does not expose its
+    /// own memoizer, so this one is created for the same locale.
+    intls: IntlLangMemoizer,
+}
+
+#[php_impl]
+impl FluentPhpBundle {
+    #[php(optional = options)]
+    fn __construct(lang: String, options: Option<&ZendHashTable>) -> PhpResult<Self> {
+        Self::new(&lang, BundleOptions::from_php(options)?)
+    }
+
+    pub fn add_resource(&mut self, resource: &Zval) -> PhpResult<()> {
+        let (arc, comments) = if resource.is_string() {
+            let source = resource.string().ok_or_else(|| {
+                PhpException::from_class::<Exception>("Failed to read string argument.".to_string())
+            })?;
+            let arc = cache::uncached_parse_string(source).map_err(cache_error_to_php)?;
+            (arc, introspect::Comments::default())
+        } else if resource.is_object() {
+            let obj = resource.object().ok_or_else(|| {
+                PhpException::from_class::<Exception>("Failed to read object argument.".to_string())
+            })?;
+            let res: &FluentPhpResource = obj.extract().map_err(|_| {
+                PhpException::from_class::<Exception>(
+                    "addResource() expects a string or FluentResource instance.".to_string(),
+                )
+            })?;
+            (Arc::clone(&res.inner), res.comments.clone())
+        } else {
+            return Err(PhpException::from_class::<Exception>(
+                "addResource() expects a string or FluentResource instance.".to_string(),
+            ));
+        };
+
+        self.add_shared_resource(arc, comments)
+    }
+
+    #[php(optional = named_args)]
+    pub fn add_function(
+        &mut self,
+        fn_name: String,
+        callable: &Zval,
+        named_args: Option<String>,
+    ) -> PhpResult<()> {
+        let named_args = match named_args {
+            Some(mode) => NamedArgs::from_php(&mode)?,
+            None => NamedArgs::Array,
+        };
+        let function = PhpFunction::new(&fn_name, callable, named_args)?;
+        let exceptions = Arc::clone(&self.function_exceptions);
+
+        let status = self
+            .bundle
+            .add_function(&fn_name, move |positional, named| {
+                function.call(positional, named, &exceptions)
+            });
+
+        match status {
+            Ok(_) => Ok(()),
+            Err(e) => Err(PhpException::from_class::<Exception>(e.to_string())),
+        }
+    }
+
+    /// Add opt-in groups of native functions: `compare` and `string`.
+    pub fn add_builtins(&mut self, groups: Vec<String>) -> PhpResult<()> {
+        for group in builtin_groups(&groups)? {
+            group
+                .add_to(&mut self.bundle)
+                .map_err(|e| PhpException::from_class::<Exception>(e.to_string()))?;
+        }
+        Ok(())
+    }
+
+    /// Use the functions of a `FunctionRegistry`, including ones added to it
+    /// later. Functions the bundle already has take precedence.
+    pub fn set_function_registry(&mut self, registry: &Zval) -> PhpResult<()> {
+        let functions = FunctionRegistry::shared_functions(registry)?;
+        self.attach_registry(functions)
+    }
+
+    pub fn format_pattern(&mut self, msg_id: String, arg_ids: &ZendHashTable) -> PhpResult<String> {
+        let args: FluentPhpArgs = arg_ids.try_into()?;
+        self.sync_registry();
+
+        self.format_value(msg_id, &args)
+    }
+
+    #[php(optional = arg_ids)]
+    pub fn format_pattern_with_errors(
+        &mut self,
+        msg_id: String,
+        arg_ids: Option<&ZendHashTable>,
+    ) -> PhpResult<ZBox<ZendHashTable>> {
+        let args: FluentPhpArgs = match arg_ids {
+            Some(arg_ids) => arg_ids.try_into()?,
+            None => FluentPhpArgs::default(),
+        };
+        self.sync_registry();
+
+        let (value, errors) = match self.get_value(&msg_id) {
+            Ok(pattern) => {
+                let mut errors = FormatErrors::default();
+                let value = self.write_pattern(pattern, &args, &mut errors)?;
+                (value, errors.messages())
+            }
+            Err(e) => {
+                let message = e.to_string();
+                self.error_policy.recover(e)?;
+                (msg_id, vec![message])
+            }
+        };
+
+        let mut ht = ZendHashTable::new();
+        ht.insert("value", value).unwrap();
+        ht.insert("errors", errors).unwrap();
+        Ok(ht)
+    }
+
+    #[php(optional = arg_ids)]
+    pub fn format_attribute(
+        &mut self,
+        msg_id: String,
+        attr_id: String,
+        arg_ids: Option<&ZendHashTable>,
+    ) -> PhpResult<String> {
+        let args: FluentPhpArgs = match arg_ids {
+            Some(arg_ids) => arg_ids.try_into()?,
+            None => FluentPhpArgs::default(),
+        };
+        self.sync_registry();
+
+        let name = format!("{}.{}", msg_id, attr_id);
+
+        let attr = match self.get_message(&msg_id) {
+            Ok(msg) => msg.get_attribute(&attr_id),
+            Err(e) => {
+                self.error_policy.recover(e)?;
+                return Ok(name);
+            }
+        };
+        let attr = match attr {
+            Some(attr) => attr,
+            None => {
+                self.error_policy
+                    .recover(FluentPhpError::AttributeNotFound { msg_id, attr_id })?;
+                return Ok(name);
+            }
+        };
+
+        self.format(name, attr.value(), &args)
+    }
+
+    #[php(optional = arg_ids)]
+    pub fn format_message(
+        &mut self,
+        msg_id: String,
+        arg_ids: Option<&ZendHashTable>,
+    ) -> PhpResult<ZBox<ZendHashTable>> {
+        let args: FluentPhpArgs = match arg_ids {
+            Some(arg_ids) => arg_ids.try_into()?,
+            None => FluentPhpArgs::default(),
+        };
+        self.sync_registry();
+
+        let mut ht = ZendHashTable::new();
+
+        let msg = match self.get_message(&msg_id) {
+            Ok(msg) => msg,
+            Err(e) => {
+                self.error_policy.recover(e)?;
+                ht.insert("value", msg_id).unwrap();
+                ht.insert("attributes", ZendHashTable::new()).unwrap();
+                return Ok(ht);
+            }
+        };
+
+        // Errors from the value and every attribute are reported together.
+        let mut errors = FormatErrors::default();
+
+        let mut value = msg
+            .value()
+            .map(|pattern| self.write_pattern(pattern, &args, &mut errors))
+            .transpose()?;
+
+        let mut attributes = msg
+            .attributes()
+            .map(|attr| {
+                let formatted = self.write_pattern(attr.value(), &args, &mut errors)?;
+                Ok((attr.id(), formatted))
+            })
+            .collect::<PhpResult<Vec<_>>>()?;
+
+        if !errors.is_empty() {
+            self.recover_resolver_error(msg_id.clone(), errors)?;
+
+            // Under `fallback`, every pattern falls back to its own id.
+            if self.error_policy == ErrorPolicy::Fallback {
+                value = value.map(|_| msg_id.clone());
+                for (id, formatted) in &mut attributes {
+                    *formatted = format!("{}.{}", msg_id, id);
+                }
+            }
+        }
+
+        let mut attributes_ht = ZendHashTable::new();
+        for (id, formatted) in attributes {
+            attributes_ht.insert(id, formatted).unwrap();
+        }
+
+        ht.insert("value", value).unwrap();
+        ht.insert("attributes", attributes_ht).unwrap();
+        Ok(ht)
+    }
+
+    fn has_message(&mut self, msg_id: String) -> PhpResult<bool> {
+        Ok(self.bundle.has_message(&msg_id))
+    }
+
+    fn has_attribute(&mut self, msg_id: String, attr_id: String) -> PhpResult<bool> {
+        Ok(self
+            .bundle
+            .get_message(&msg_id)
+            .is_some_and(|msg| msg.get_attribute(&attr_id).is_some()))
+    }
+
+    /// The ids of the bundle's messages, in the order they were added.
+    pub fn get_message_ids(&self) -> Vec<String> {
+        let mut seen = HashSet::new();
+        self.resources
+            .iter()
+            .flat_map(|(resource, _)| introspect::message_ids(resource))
+            .filter(|id| seen.insert(*id))
+            .map(String::from)
+            .collect()
+    }
+
+    /// Describe a message, or return null if the bundle has no such message.
+    pub fn get_message_info(&self, msg_id: String) -> Option<ZBox<ZendHashTable>> {
+        // fluent-bundle keeps the first definition of a message id.
+        self.resources
+            .iter()
+            .find_map(|(resource, comments)| message_info(resource, comments, &msg_id))
+    }
+
+    pub fn get_locales(&self) -> Vec<Locale> {
+        self.bundle
+            .locales
+            .iter()
+            .map(|lang_id| Locale {
+                lang_id: lang_id.clone(),
+            })
+            .collect()
+    }
+
+    /// `"ltr"` or `"rtl"`, from the script of the bundle's locale.
+    pub fn get_direction(&self) -> String {
+        locale::direction(&self.bundle.locales[0]).to_string()
+    }
+
+    /// Wrap placeables in Unicode isolation marks, so interpolated text of the
+    /// opposite direction does not reorder the text around it.
+    pub fn set_use_isolating(&mut self, use_isolating: bool) {
+        self.bundle.set_use_isolating(use_isolating);
+    }
+
+    /// Transform the literal text of messages with a pseudo-localization
+    /// strategy, or stop transforming it with `null`.
+    pub fn set_pseudo_localization(&mut self, strategy: Option<String>) -> PhpResult<()> {
+        self.pseudo_localization = strategy.as_deref().map(pseudo_strategy).transpose()?;
+        self.update_transform();
+        Ok(())
+    }
+
+    /// Pass the literal text of messages through a PHP callable, or stop with
+    /// `null`. Results are cached by text when the callable is pure.
+    #[php(optional = pure)]
+    pub fn set_transform(&mut self, transform: &Zval, pure: Option<bool>) -> PhpResult<()> {
+        self.transform = if transform.is_null() {
+            None
+        } else {
+            Some(Arc::new(PhpTransform::new(
+                transform,
+                pure.unwrap_or(false),
+            )?))
+        };
+        self.update_transform();
+        Ok(())
+    }
+
+    /// Format the numbers, dates, lists, booleans and objects placeables show
+    /// with a PHP callable, or stop with `null`.
+    pub fn set_formatter(&mut self, formatter: &Zval) -> PhpResult<()> {
+        self.formatter = if formatter.is_null() {
+            None
+        } else {
+            Some(Arc::new(PhpFormatter::new(formatter)?))
+        };
+        Ok(())
+    }
+
+    #[php(optional = rule_type)]
+    pub fn get_plural_category(
+        &self,
+        number: &Zval,
+        rule_type: Option<String>,
+    ) -> PhpResult<String> {
+        let rule_type = plural_rule_type(rule_type)?;
+        let operands = if number.is_long() {
+            PluralOperands::try_from(number.long().unwrap()).ok()
+        } else if number.is_double() {
+            PluralOperands::try_from(number.double().unwrap()).ok()
+        } else if let Some(number) = number.str() {
+            PluralOperands::try_from(number.trim()).ok()
+        } else {
+            return Err(PhpException::from_class::<Exception>(
+                "getPluralCategory() expects an int, float or numeric string.".to_string(),
+            ));
+        };
+        let Some(operands) = operands else {
+            return Err(PhpException::from_class::<Exception>(format!(
+                "Invalid number \"{}\".",
+                FluentPhpValue::from_zval(number)
+                    .map(|number| number.to_string())
+                    .unwrap_or_default()
+            )));
+        };
+
+        plural::category(&self.intls, rule_type, operands)
+            .map(|category| category.to_string())
+            .map_err(|e| PhpException::from_class::<Exception>(e.to_string()))
+    }
+
+    #[php(optional = rule_type)]
+    pub fn get_plural_categories(&self, rule_type: Option<String>) -> PhpResult<Vec<String>> {
+        let rule_type = plural_rule_type(rule_type)?;
+        plural::categories(&self.intls, rule_type)
+            .map(|categories| categories.into_iter().map(String::from).collect())
+            .map_err(|e| PhpException::from_class::<Exception>(e.to_string()))
+    }
+
+    pub fn set_error_policy(&mut self, policy: String) -> PhpResult<()> {
+        self.error_policy = ErrorPolicy::from_php(&policy)?;
+        Ok(())
+    }
+
+    pub fn get_error_policy(&self) -> String {
+        self.error_policy.as_str().to_string()
+    }
+
+    pub fn set_function_exception_mode(&mut self, mode: String) -> PhpResult<()> {
+        self.function_exception_mode = FunctionExceptionMode::from_php(&mode)?;
+        Ok(())
+    }
 
-    fn as_string_threadsafe(
-        &self,
-        _intls: &intl_memoizer::concurrent::IntlLangMemoizer,
-    ) -> std::borrow::Cow<'static, str> {
-        self.stringify()
+    pub fn get_function_exception_mode(&self) -> String {
+        self.function_exception_mode.as_str().to_string()
     }
 }
 
-enum FluentPhpValue {
-    Bool(bool),
-    Double(f64),
-    Long(i64),
-    Str(String),
-    Zval(Zval),
-    None,
-}
+impl FluentPhpBundle {
+    fn new(lang: &str, options: BundleOptions) -> PhpResult<Self> {
+        let lang_id = locale::parse_language_identifier(lang).map_err(locale_error_to_php)?;
 
-impl Display for FluentPhpValue {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::Str(s) => write!(f, "{}", &s),
-            Self::Bool(true) => write!(f, "true"),
-            Self::Bool(false) => write!(f, "false"),
-            Self::Long(n) => write!(f, "{}", n),
-            Self::Double(fl) => write!(f, "{}", fl),
-            Self::None => write!(f, ""),
-            Self::Zval(zv) => match zv {
-                val if val.is_long() => write!(f, "{}", val.long().unwrap()),
-                val if val.is_double() => write!(f, "{}", val.double().unwrap()),
-                val if val.is_string() => write!(f, "{}", val.str().unwrap()),
-                _ => write!(f, "{}", zv.str().unwrap_or("")),
-            },
+        let intls = IntlLangMemoizer::new(lang_id.clone());
+        let mut bundle = FluentBundle::new(vec![lang_id]);
+        let missing_values: Arc<MissingValues> = Arc::default();
+        let (prop_missing, key_missing) =
+            (Arc::clone(&missing_values), Arc::clone(&missing_values));
+
+        bundle.set_formatter(Some(apply_formatter));
+        bundle
+            .add_function("NUMBER", number::builtin)
+            .and_then(|_| bundle.add_function("DATETIME", datetime_builtin))
+            .and_then(|_| bundle.add_function("LIST", list::builtin))
+            .and_then(|_| {
+                bundle.add_function("PROP", move |positional, _| {
+                    Access::Property.call(positional, &prop_missing)
+                })
+            })
+            .and_then(|_| {
+                bundle.add_function("KEY", move |positional, _| {
+                    Access::Key.call(positional, &key_missing)
+                })
+            })
+            .map_err(|e| FluentPhpError::from_error(vec![e]))?;
+        let mut bundle = Self {
+            bundle,
+            error_policy: ErrorPolicy::default(),
+            function_exceptions: Arc::default(),
+            function_exception_mode: FunctionExceptionMode::default(),
+            missing_values,
+            registry: None,
+            registry_names: HashSet::new(),
+            pseudo_localization: None,
+            transform: None,
+            formatter: None,
+            resources: Vec::new(),
+            intls,
+        };
+        options.apply(&mut bundle);
+        Ok(bundle)
+    }
+
+    /// Set the fluent-bundle transform for the PHP transform and
+    /// pseudo-localization strategy.
+    fn update_transform(&mut self) {
+        let transform: Option<fn(&str) -> Cow<'_, str>> = match self.transform {
+            Some(_) => Some(apply_transform),
+            None => self.pseudo_localization.map(pseudo::Strategy::transform),
+        };
+        self.bundle.set_transform(transform);
+    }
+
+    fn locale(&self) -> String {
+        self.bundle
+            .locales
+            .first()
+            .map(|lang_id| lang_id.to_string())
+            .unwrap_or_default()
+    }
+
+    fn add_shared_resource(
+        &mut self,
+        resource: Arc<FluentResource>,
+        comments: introspect::Comments,
+    ) -> PhpResult<()> {
+        // fluent-bundle keeps the entries that do not clash even when adding
+        // fails, so the resource is recorded either way.
+        self.resources.push((Arc::clone(&resource), comments));
+        match self.bundle.add_resource(resource) {
+            Ok(_) => Ok(()),
+            Err(errors) => Err(FluentPhpError::from_error(errors).into()),
         }
     }
-}
 
-impl Clone for FluentPhpValue {
-    fn clone(&self) -> Self {
-        match self {
-            Self::Str(val) => Self::Str(val.clone()),
-            Self::Bool(val) => Self::Bool(*val),
-            Self::Long(val) => Self::Long(*val),
-            Self::Double(val) => Self::Double(*val),
-            Self::None => Self::None,
-            Self::Zval(val) => Self::Zval(val.shallow_clone()),
+    fn attach_registry(&mut self, functions: Arc<RegistryFunctions>) -> PhpResult<()> {
+        match &self.registry {
+            Some(attached) if Arc::ptr_eq(attached, &functions) => return Ok(()),
+            Some(_) => {
+                return Err(PhpException::from_class::<Exception>(
+                    "The bundle already uses another FunctionRegistry.".to_string(),
+                ));
+            }
+            None => self.registry = Some(functions),
         }
+
+        self.sync_registry();
+        Ok(())
     }
-}
 
-impl TryFrom<&FluentValue<'_>> for FluentPhpValue {
-    type Error = FluentPhpError;
+    /// Add a dispatcher for each registry function the bundle has not seen yet.
+    /// fluent-bundle cannot replace or remove functions, so dispatchers look up
+    /// the registry's current function when called.
+    ///
+    /// A name the bundle already has a function for keeps that function, so
+    /// the bundle's own functions take precedence over the registry's. Once a
+    /// dispatcher has the name, the bundle cannot add a function of its own
+    /// for it, as for any name it has.
+    fn sync_registry(&mut self) {
+        let Some(registry) = &self.registry else {
+            return;
+        };
 
-    fn try_from(value: &FluentValue<'_>) -> Result<Self, FluentPhpError> {
-        let value = match value {
-            FluentValue::String(s) => Self::Str(s.clone().into()),
-            FluentValue::Number(n) => Self::Double(n.value),
-            FluentValue::None => Self::None,
-            FluentValue::Custom(val) => {
-                if let Some(val) = val.as_ref().as_any().downcast_ref::<FluentPhpObjectValue>() {
-                    FluentPhpValue::Zval(val.lock().shallow_clone())
-                } else if let Some(val) = val.as_ref().as_any().downcast_ref::<FluentPhpBoolValue>()
-                {
-                    val.to_php_value()
-                } else {
-                    FluentPhpValue::None
-                }
+        let lang = builtins::bundle_lang(&self.bundle);
+        for name in registry.names() {
+            if !self.registry_names.insert(name.clone()) {
+                continue;
             }
-            FluentValue::Error => {
-                return Err(FluentPhpError::Message(
-                    "Unsupported variable type".to_string(),
-                ));
+
+            let dispatcher = RegistryDispatcher {
+                name: name.clone(),
+                functions: Arc::clone(registry),
+                lang: lang.clone(),
+                exceptions: Arc::clone(&self.function_exceptions),
+                missing_values: Arc::clone(&self.missing_values),
+            };
+            let added = self.bundle.add_function(&name, move |positional, named| {
+                dispatcher.call(positional, named)
+            });
+            // Adding only fails for a name the bundle already has.
+            debug_assert!(matches!(
+                added,
+                Ok(()) | Err(FluentError::Overriding { .. })
+            ));
+        }
+    }
+
+    fn format_value(&self, msg_id: String, args: &FluentPhpArgs) -> PhpResult<String> {
+        let pattern = match self.get_value(&msg_id) {
+            Ok(pattern) => pattern,
+            Err(e) => {
+                self.error_policy.recover(e)?;
+                return Ok(msg_id);
             }
         };
 
-        Ok(value)
+        self.format(msg_id, pattern, args)
     }
-}
 
-impl From<FluentPhpValue> for FluentValue<'static> {
-    fn from(value: FluentPhpValue) -> Self {
-        match value {
-            FluentPhpValue::Str(val) => Self::String(val.into()),
-            FluentPhpValue::Long(val) => Self::Number(val.into()),
-            FluentPhpValue::Double(val) => Self::Number(val.into()),
-            FluentPhpValue::Bool(val) => Self::Custom(Box::new(FluentPhpBoolValue(val))),
-            FluentPhpValue::Zval(val) => {
-                Self::Custom(Box::new(FluentPhpObjectValue::new(val.shallow_clone())))
+    fn get_message(&self, msg_id: &str) -> Result<FluentMessage<'_>, FluentPhpError> {
+        self.bundle
+            .get_message(msg_id)
+            .ok_or_else(|| FluentPhpError::Message(format!("Message \"{}\" not found.", msg_id)))
+    }
+
+    fn get_value(&self, msg_id: &str) -> Result<&Pattern<&str>, FluentPhpError> {
+        self.get_message(msg_id)?
+            .value()
+            .ok_or_else(|| FluentPhpError::Message(format!("Message \"{}\" has no value.", msg_id)))
+    }
+
+    /// Format one pattern of a message. `msg_id` names the pattern in resolver
+    /// errors (`id` for values, `id.attr` for attributes).
+    fn format<'b>(
+        &'b self,
+        msg_id: String,
+        pattern: &'b Pattern<&'b str>,
+        args: &FluentPhpArgs,
+    ) -> PhpResult<String> {
+        let mut errors = FormatErrors::default();
+
+        let value = self.write_pattern(pattern, args, &mut errors)?;
+
+        if !errors.is_empty() {
+            self.recover_resolver_error(msg_id.clone(), errors)?;
+
+            if self.error_policy == ErrorPolicy::Fallback {
+                return Ok(msg_id);
             }
-            FluentPhpValue::None => Self::None,
         }
+
+        Ok(value)
     }
-}
 
-impl FromZval<'_> for FluentPhpValue {
-    const TYPE: DataType = DataType::Mixed;
-    fn from_zval(zv: &Zval) -> Option<Self> {
-        let val = if zv.is_string() {
-            FluentPhpValue::Str(zv.string().unwrap())
-        } else if zv.is_long() {
-            FluentPhpValue::Long(zv.long().unwrap())
-        } else if zv.is_double() {
-            FluentPhpValue::Double(zv.double().unwrap())
-        } else if zv.is_null() {
-            FluentPhpValue::None
-        } else if zv.is_bool() {
-            FluentPhpValue::Bool(zv.bool().unwrap())
-        } else if zv.is_object() {
-            FluentPhpValue::Zval(zv.shallow_clone())
+    fn write_pattern<'b>(
+        &'b self,
+        pattern: &'b Pattern<&'b str>,
+        args: &FluentPhpArgs,
+        errors: &mut FormatErrors,
+    ) -> PhpResult<String> {
+        self.check_array_args(pattern, args)?;
+
+        // Fluent only selects on strings and numbers, so selectors on other
+        // values are redirected to what they select by, while placeables still
+        // show the value itself.
+        let selectors: Vec<(selector::Selector, String, FluentValue)> =
+            selector::selectors(pattern)
+                .into_iter()
+                .enumerate()
+                .filter_map(|(index, selector)| {
+                    let value = selector_value(&selector.value(args)?, &self.intls)?;
+                    let renamed = format!("{}{}{}", selector.variable(), SELECTOR_SUFFIX, index);
+                    Some((selector, renamed, value))
+                })
+                .collect();
+
+        // Entered even without hooks of our own: every bundle formats through
+        // `apply_formatter()`, which must not find those of a bundle this one
+        // is formatted from within, e.g. in its formatter callback.
+        let _hooks = HookScope::enter(ActiveHooks {
+            transform: self.transform.clone(),
+            formatter: self.formatter.clone(),
+            pseudo_localization: self.pseudo_localization,
+            exceptions: Arc::clone(&self.function_exceptions),
+        });
+        let value = if selectors.is_empty() {
+            self.bundle
+                .format_pattern(pattern, Some(args), &mut errors.resolver)
+                .into_owned()
         } else {
-            return None;
-        };
+            let mut selector_args =
+                FluentArgs::with_capacity(args.iter().count() + selectors.len());
+            for (name, value) in args.iter() {
+                selector_args.set(name.to_string(), value.clone());
+            }
+            for (_, renamed, value) in &selectors {
+                selector_args.set(renamed.clone(), value.clone());
+            }
 
-        Some(val)
+            let pattern = selector::replace_selectors(pattern, &|selector| {
+                selectors
+                    .iter()
+                    .find(|(selected, _, _)| selected == selector)
+                    .map(|(_, renamed, _)| renamed.as_str())
+            });
+            self.bundle
+                .format_pattern(&pattern, Some(&selector_args), &mut errors.resolver)
+                .into_owned()
+        };
+        errors.resolver.extend(self.missing_values.take());
+        errors.exceptions.extend(self.function_exceptions.take());
+        Ok(value)
     }
-}
 
-impl IntoZval for FluentPhpValue {
-    const TYPE: DataType = DataType::Mixed;
-    const NULLABLE: bool = false;
+    /// Reject associative-array arguments that `pattern`, or a message it
+    /// references, uses other than as the first argument of `KEY()`.
+    fn check_array_args<'b>(
+        &'b self,
+        pattern: &'b Pattern<&'b str>,
+        args: &FluentPhpArgs,
+    ) -> Result<(), FluentPhpError> {
+        if args.arrays.is_empty() {
+            return Ok(());
+        }
 
-    fn set_zval(self, zv: &mut Zval, persistent: bool) -> ext_php_rs::error::Result<()> {
-        match self {
-            Self::Str(val) => zv.set_string(&val, persistent)?,
-            Self::Bool(val) => zv.set_bool(val),
-            Self::Long(val) => zv.set_long(val),
-            Self::Double(val) => zv.set_double(val),
-            Self::Zval(val) => *zv = val,
-            Self::None => zv.set_null(),
-        };
+        let mut patterns = vec![pattern];
+        let mut visited = HashSet::new();
+        while let Some(pattern) = patterns.pop() {
+            let uses = introspect::ArgumentUses::new(pattern);
+            if let Some(name) = args
+                .arrays
+                .iter()
+                .find(|name| uses.variables.contains(&name.as_str()))
+            {
+                return Err(unsupported_argument(name, DataType::Array));
+            }
+
+            for (id, attribute) in uses.messages {
+                if !visited.insert((id, attribute)) {
+                    continue;
+                }
+                let Some(message) = self.bundle.get_message(id) else {
+                    continue;
+                };
+                patterns.extend(match attribute {
+                    Some(attribute) => message.get_attribute(attribute).map(|attr| attr.value()),
+                    None => message.value(),
+                });
+            }
+        }
         Ok(())
     }
+
+    /// Apply the error policy to resolver errors. In `rethrow` mode, an
+    /// exception thrown by a PHP function is thrown as-is instead.
+    fn recover_resolver_error(&self, msg_id: String, mut errors: FormatErrors) -> PhpResult<()> {
+        if self.error_policy != ErrorPolicy::Throw || errors.exceptions.is_empty() {
+            return self
+                .error_policy
+                .recover(FluentPhpError::ResolverError { msg_id, errors });
+        }
+
+        if self.function_exception_mode == FunctionExceptionMode::Rethrow {
+            let exception = errors.exceptions.swap_remove(0);
+            return Err(PhpException::default(exception.description.clone())
+                .with_object(exception.into_zval()));
+        }
+
+        // Keep the first function exception (and its trace) as the `previous`
+        // of the ResolverException. The property is private to `Exception`, so
+        // it is set through the constructor, as `new` with `$previous` would.
+        let messages = errors.messages();
+        let message = resolver_message(&msg_id, messages.clone());
+        let previous = errors.exceptions.swap_remove(0).into_zval();
+        let exception = ResolverException::new_object(message.clone(), messages);
+        if let Some(object) = exception.object() {
+            object
+                .try_call_method("__construct", vec![&message.as_str(), &0i64, &previous])
+                .map_err(|e| PhpException::from_class::<Exception>(e.to_string()))?;
+        }
+        Err(PhpException::default(message).with_object(exception))
+    }
 }
 
-// -- FluentBundle PHP class --
+// -- Localization PHP class --
+
+/// Replaced with each locale in `Localization` resource paths.
+const LOCALE_PLACEHOLDER: &str = "{locale}";
 
 #[php_class]
-#[php(name = "FluentPhp\\FluentBundle")]
-struct FluentPhpBundle {
-    bundle: FluentBundle<Arc<FluentResource>>,
+#[php(name = "FluentPhp\\Localization")]
+struct Localization {
+    /// One bundle per locale, in fallback order.
+    bundles: Vec<FluentPhpBundle>,
+    error_policy: ErrorPolicy,
 }
 
 #[php_impl]
-impl FluentPhpBundle {
-    fn __construct(lang: String) -> PhpResult<Self> {
-        let lang_id = match lang.parse::<LanguageIdentifier>() {
-            Ok(lang_id) => lang_id,
-            Err(_e) => {
-                return Err(PhpException::from_class::<Exception>(
-                    "Invalid language identifier.".to_string(),
-                ));
+impl Localization {
+    #[php(optional = functions)]
+    fn __construct(
+        locales: Vec<String>,
+        resource_paths: Vec<String>,
+        functions: Option<&Zval>,
+    ) -> PhpResult<Self> {
+        if locales.is_empty() {
+            return Err(PhpException::from_class::<Exception>(
+                "Localization requires at least one locale.".to_string(),
+            ));
+        }
+
+        let functions = functions
+            .filter(|functions| !functions.is_null())
+            .map(FunctionRegistry::shared_functions)
+            .transpose()?;
+
+        let options = BundleOptions::from_ini();
+        let mut bundles = Vec::with_capacity(locales.len());
+        let mut found = vec![false; resource_paths.len()];
+        for lang in &locales {
+            let mut bundle = FluentPhpBundle::new(lang, options)?;
+            if let Some(functions) = &functions {
+                bundle.attach_registry(Arc::clone(functions))?;
             }
-        };
 
-        let mut bundle = FluentBundle::new(vec![lang_id]);
+            for (path, found) in resource_paths.iter().zip(&mut found) {
+                let path = path.replace(LOCALE_PLACEHOLDER, lang);
+                let resource = match cache::get_or_parse_file(&path) {
+                    Ok(resource) => resource,
+                    // A locale without this file falls back to the next locale.
+                    Err(cache::CacheError::Io(e)) if e.kind() == std::io::ErrorKind::NotFound => {
+                        continue;
+                    }
+                    Err(e) => return Err(cache_file_error_to_php(&path, e)),
+                };
+                *found = true;
+                bundle.add_shared_resource(resource, introspect::Comments::default())?;
+            }
 
-        bundle.set_use_isolating(false);
-        Ok(Self { bundle })
-    }
+            bundles.push(bundle);
+        }
 
-    pub fn add_resource(&mut self, resource: &Zval) -> PhpResult<()> {
-        let arc = if resource.is_string() {
-            let source = resource.string().ok_or_else(|| {
-                PhpException::from_class::<Exception>("Failed to read string argument.".to_string())
-            })?;
-            cache::uncached_parse_string(source).map_err(cache_error_to_php)?
-        } else if resource.is_object() {
-            let obj = resource.object().ok_or_else(|| {
-                PhpException::from_class::<Exception>("Failed to read object argument.".to_string())
-            })?;
-            let res: &FluentPhpResource = obj.extract().map_err(|_| {
-                PhpException::from_class::<Exception>(
-                    "addResource() expects a string or FluentResource instance.".to_string(),
-                )
-            })?;
-            Arc::clone(&res.inner)
-        } else {
-            return Err(PhpException::from_class::<Exception>(
-                "addResource() expects a string or FluentResource instance.".to_string(),
-            ));
-        };
+        // A path no locale has is more likely a mistake than a translation
+        // that is still to come.
+        if let Some((path, _)) = resource_paths
+            .iter()
+            .zip(&found)
+            .find(|(_, found)| !**found)
+        {
+            return Err(PhpException::from_class::<Exception>(format!(
+                "Resource \"{}\" not found in locales: {}.",
+                path,
+                locales.join(", ")
+            )));
+        }
 
-        match self.bundle.add_resource(arc) {
-            Ok(_) => Ok(()),
-            Err(errors) => Err(FluentPhpError::from_error(errors).into()),
+        Ok(Self {
+            bundles,
+            error_policy: options.error_policy,
+        })
+    }
+
+    #[php(optional = named_args)]
+    pub fn add_function(
+        &mut self,
+        fn_name: String,
+        callable: &Zval,
+        named_args: Option<String>,
+    ) -> PhpResult<()> {
+        for bundle in &mut self.bundles {
+            bundle.add_function(fn_name.clone(), callable, named_args.clone())?;
         }
+        Ok(())
     }
 
-    pub fn add_function(&mut self, fn_name: String, callable: &Zval) -> PhpResult<()> {
-        let callable = ZendCallable::new_owned(callable.shallow_clone()).unwrap();
-        let callable = ThreadSafeZendCallable::new(callable);
+    pub fn add_builtins(&mut self, groups: Vec<String>) -> PhpResult<()> {
+        for bundle in &mut self.bundles {
+            bundle.add_builtins(groups.clone())?;
+        }
+        Ok(())
+    }
 
-        let status = self
-            .bundle
-            .add_function(&fn_name, move |oargs, _named_args| {
-                let args: Result<Vec<FluentPhpValue>, FluentPhpError> =
-                    oargs.iter().map(|p| p.try_into()).collect();
-
-                if let Ok(args) = args {
-                    let args: Vec<&dyn IntoZvalDyn> =
-                        args.iter().map(|p| p as &dyn IntoZvalDyn).collect();
-
-                    return callable
-                        .lock()
-                        .try_call(args)
-                        .map(|value| zval_to_fluent_value(value))
-                        .unwrap_or(FluentValue::Error);
-                };
+    /// Use the functions of a `FunctionRegistry` in every locale's bundle, as
+    /// passing it to the constructor does.
+    pub fn set_function_registry(&mut self, registry: &Zval) -> PhpResult<()> {
+        let functions = FunctionRegistry::shared_functions(registry)?;
+        for bundle in &mut self.bundles {
+            bundle.attach_registry(Arc::clone(&functions))?;
+        }
+        Ok(())
+    }
 
-                FluentValue::Error
-            });
+    #[php(optional = arg_ids)]
+    pub fn format_pattern(
+        &mut self,
+        msg_id: String,
+        arg_ids: Option<&ZendHashTable>,
+    ) -> PhpResult<String> {
+        let args: FluentPhpArgs = match arg_ids {
+            Some(arg_ids) => arg_ids.try_into()?,
+            None => FluentPhpArgs::default(),
+        };
+        for bundle in &mut self.bundles {
+            bundle.sync_registry();
+        }
 
-        match status {
-            Ok(_) => Ok(()),
-            Err(e) => Err(PhpException::from_class::<Exception>(e.to_string())),
+        match self.find_bundle(&msg_id) {
+            Ok(bundle) => bundle.format_value(msg_id, &args),
+            Err(e) => {
+                self.error_policy.recover(e)?;
+                Ok(msg_id)
+            }
         }
     }
 
-    pub fn format_pattern(&mut self, msg_id: String, arg_ids: &ZendHashTable) -> PhpResult<String> {
-        let args: FluentPhpArgs = arg_ids.try_into()?;
+    pub fn has_message(&self, msg_id: String) -> bool {
+        self.bundles
+            .iter()
+            .any(|bundle| bundle.bundle.has_message(&msg_id))
+    }
 
-        // Getting errors
-        let mut errors = vec![];
+    /// The locale whose bundle serves `msg_id`, or null if no locale has it.
+    pub fn get_message_locale(&self, msg_id: String) -> Option<String> {
+        self.bundles
+            .iter()
+            .find(|bundle| bundle.bundle.has_message(&msg_id))
+            .map(FluentPhpBundle::locale)
+    }
 
-        // Getting message
-        let msg = match self.bundle.get_message(&msg_id) {
-            Some(msg) => msg,
-            None => {
-                return Err(PhpException::from_class::<Exception>(format!(
-                    "Message \"{}\" not found.",
-                    msg_id
-                )));
-            }
-        };
+    pub fn get_locales(&self) -> Vec<String> {
+        self.bundles.iter().map(FluentPhpBundle::locale).collect()
+    }
 
-        // Formatting pattern
-        let pattern = match msg.value() {
-            Some(value) => value,
-            None => {
-                return Err(PhpException::from_class::<Exception>(format!(
-                    "Message \"{}\" has no value.",
-                    msg_id
-                )));
-            }
-        };
+    pub fn set_error_policy(&mut self, policy: String) -> PhpResult<()> {
+        self.error_policy = ErrorPolicy::from_php(&policy)?;
+        for bundle in &mut self.bundles {
+            bundle.error_policy = self.error_policy;
+        }
+        Ok(())
+    }
 
-        let value = self
-            .bundle
-            .format_pattern(pattern, Some(&args), &mut errors);
+    pub fn get_error_policy(&self) -> String {
+        self.error_policy.as_str().to_string()
+    }
 
-        if !errors.is_empty() {
-            return Err(FluentPhpError::ResolverError { msg_id, errors }.into());
+    pub fn set_function_exception_mode(&mut self, mode: String) -> PhpResult<()> {
+        let mode = FunctionExceptionMode::from_php(&mode)?;
+        for bundle in &mut self.bundles {
+            bundle.function_exception_mode = mode;
         }
+        Ok(())
+    }
 
-        Ok(value.into_owned())
+    pub fn get_function_exception_mode(&self) -> String {
+        self.bundles[0].get_function_exception_mode()
     }
+}
 
-    fn has_message(&mut self, msg_id: String) -> PhpResult<bool> {
-        Ok(self.bundle.has_message(&msg_id))
+impl Localization {
+    fn find_bundle(&self, msg_id: &str) -> Result<&FluentPhpBundle, FluentPhpError> {
+        self.bundles
+            .iter()
+            .find(|bundle| bundle.bundle.has_message(msg_id))
+            .ok_or_else(|| {
+                let locales = self
+                    .bundles
+                    .iter()
+                    .map(FluentPhpBundle::locale)
+                    .collect::<Vec<_>>();
+                FluentPhpError::Message(format!(
+                    "Message \"{}\" not found in locales: {}.",
+                    msg_id,
+                    locales.join(", ")
+                ))
+            })
     }
 }
 
@@ -836,6 +2994,21 @@ extern "C" fn module_startup(_type: i32, module_number: i32) -> i32 {
                 "metadata".to_string(),
                 &IniEntryPermission::System,
             ),
+            IniEntryDef::new(
+                "fluent.error_policy".to_string(),
+                "throw".to_string(),
+                &IniEntryPermission::All,
+            ),
+            IniEntryDef::new(
+                "fluent.use_isolating".to_string(),
+                "0".to_string(),
+                &IniEntryPermission::All,
+            ),
+            IniEntryDef::new(
+                "fluent.pseudo_localization".to_string(),
+                "".to_string(),
+                &IniEntryPermission::All,
+            ),
         ],
         module_number,
     );
@@ -878,13 +3051,18 @@ extern "C" fn module_startup(_type: i32, module_number: i32) -> i32 {
 #[php(startup = module_startup)]
 pub fn get_module(module: ModuleBuilder) -> ModuleBuilder {
     module
+        .interface::<PhpInterfacePhpFluentValue>()
         .class::<Exception>()
         .class::<ParserException>()
         .class::<ResolverException>()
         .class::<CacheException>()
+        .class::<AttributeNotFoundException>()
+        .class::<FunctionRegistry>()
         .class::<FluentPhpBundle>()
         .class::<FluentPhpResource>()
         .class::<ResourceCache>()
+        .class::<Localization>()
+        .class::<Locale>()
         .info_function(php_module_info)
 }
 
@@ -918,4 +3096,17 @@ mod tests {
             assert!(parse_ini_bool(value), "{value:?} should parse as true");
         }
     }
+
+    #[test]
+    fn error_policy_parses_case_insensitively() {
+        assert_eq!(ErrorPolicy::parse("throw"), Some(ErrorPolicy::Throw));
+        assert_eq!(ErrorPolicy::parse(" Warn "), Some(ErrorPolicy::Warn));
+        assert_eq!(ErrorPolicy::parse("FALLBACK"), Some(ErrorPolicy::Fallback));
+        assert_eq!(ErrorPolicy::parse("ignore"), None);
+        assert_eq!(ErrorPolicy::parse(""), None);
+
+        for policy in [ErrorPolicy::Throw, ErrorPolicy::Warn, ErrorPolicy::Fallback] {
+            assert_eq!(ErrorPolicy::parse(policy.as_str()), Some(policy));
+        }
+    }
 }
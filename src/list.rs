@@ -0,0 +1,265 @@
+use std::borrow::Cow;
+
+use fluent::types::{FluentNumber, FluentType};
+use fluent::{FluentArgs, FluentValue};
+use icu_list::{ListError, ListFormatter as IcuListFormatter, ListLength};
+use intl_memoizer::{IntlLangMemoizer, Memoizable};
+use unic_langid::LanguageIdentifier;
+
+use crate::{locale, number};
+
+// -- Values --
+
+/// A list of values, such as a PHP array, with its `LIST()` options.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FluentList {
+    pub items: Vec<FluentValue<'static>>,
+    pub options: FluentListOptions,
+}
+
+impl FluentList {
+    pub fn new(items: Vec<FluentValue<'static>>) -> Self {
+        Self {
+            items,
+            options: FluentListOptions::default(),
+        }
+    }
+
+    /// The number of items, which selects plural variants, directly or through
+    /// `NUMBER()`.
+    pub fn count(&self) -> FluentNumber {
+        FluentNumber::from(self.items.len())
+    }
+
+    /// Join the items for the memoizer's locale, e.g. `A, B, and C`.
+    pub fn format(&self, intls: &IntlLangMemoizer) -> String {
+        let items: Vec<String> = self.items.iter().map(|item| show(item, intls)).collect();
+        intls
+            .with_try_get::<ListFormatter, _, _>(self.options, |formatter| {
+                formatter.0.format_to_string(items.iter())
+            })
+            .unwrap_or_else(|_| items.join(", "))
+    }
+}
+
+impl FluentType for FluentList {
+    fn duplicate(&self) -> Box<dyn FluentType + Send> {
+        Box::new(self.clone())
+    }
+
+    fn as_string(&self, intls: &IntlLangMemoizer) -> Cow<'static, str> {
+        self.format(intls).into()
+    }
+
+    fn as_string_threadsafe(
+        &self,
+        _intls: &intl_memoizer::concurrent::IntlLangMemoizer,
+    ) -> Cow<'static, str> {
+        // Bundles in this extension are not concurrent.
+        let items: Vec<Cow<str>> = self
+            .items
+            .iter()
+            .map(|item| match item {
+                FluentValue::String(s) => s.clone(),
+                FluentValue::Number(n) => n.as_string(),
+                _ => Cow::Borrowed(""),
+            })
+            .collect();
+        items.join(", ").into()
+    }
+}
+
+/// An item as it would be shown on its own in a placeable.
+fn show(item: &FluentValue, intls: &IntlLangMemoizer) -> String {
+    match item {
+        FluentValue::String(s) => s.to_string(),
+        FluentValue::Number(n) => {
+            number::format_value(item, intls).unwrap_or_else(|| n.as_string().into_owned())
+        }
+        FluentValue::Custom(custom) => custom.as_string(intls).into_owned(),
+        FluentValue::None | FluentValue::Error => String::new(),
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum ListType {
+    /// "A, B, and C"
+    #[default]
+    Conjunction,
+    /// "A, B, or C"
+    Disjunction,
+    /// "A, B, C", for units such as `3 ft, 7 in`.
+    Unit,
+}
+
+impl ListType {
+    fn parse(list_type: &str) -> Option<Self> {
+        match list_type {
+            "conjunction" => Some(Self::Conjunction),
+            "disjunction" => Some(Self::Disjunction),
+            "unit" => Some(Self::Unit),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum ListStyle {
+    #[default]
+    Long,
+    Short,
+    Narrow,
+}
+
+impl ListStyle {
+    fn parse(style: &str) -> Option<Self> {
+        match style {
+            "long" => Some(Self::Long),
+            "short" => Some(Self::Short),
+            "narrow" => Some(Self::Narrow),
+            _ => None,
+        }
+    }
+}
+
+/// `LIST()` options, named like `Intl.ListFormat`'s.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct FluentListOptions {
+    pub list_type: ListType,
+    pub style: ListStyle,
+}
+
+impl FluentListOptions {
+    /// Apply `type` and `style`; unknown values are ignored, like unknown
+    /// `NUMBER()` options.
+    pub fn merge(&mut self, args: &FluentArgs) {
+        for (key, value) in args.iter() {
+            let FluentValue::String(value) = value else {
+                continue;
+            };
+            match key {
+                "type" => self.list_type = ListType::parse(value).unwrap_or(self.list_type),
+                "style" => self.style = ListStyle::parse(value).unwrap_or(self.style),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// The `LIST()` builtin: apply `type` and `style` to a list argument. A single
+/// value is a list of one.
+pub fn builtin<'a>(positional: &[FluentValue<'a>], named: &FluentArgs) -> FluentValue<'a> {
+    let mut list = match positional.first() {
+        Some(FluentValue::Custom(value)) => {
+            match value.as_ref().as_any().downcast_ref::<FluentList>() {
+                Some(list) => list.clone(),
+                None => FluentList::new(vec![positional[0].into_owned()]),
+            }
+        }
+        Some(FluentValue::None | FluentValue::Error) | None => return FluentValue::Error,
+        Some(value) => FluentList::new(vec![value.into_owned()]),
+    };
+    list.options.merge(named);
+
+    FluentValue::Custom(Box::new(list))
+}
+
+// -- Formatter --
+
+/// A CLDR list formatter for one locale and set of options, memoized per
+/// bundle.
+pub struct ListFormatter(IcuListFormatter);
+
+impl Memoizable for ListFormatter {
+    type Args = FluentListOptions;
+    type Error = ListError;
+
+    fn construct(lang: LanguageIdentifier, options: Self::Args) -> Result<Self, Self::Error> {
+        let locale = (&locale::to_icu_locale(&lang)).into();
+        let length = match options.style {
+            ListStyle::Long => ListLength::Wide,
+            ListStyle::Short => ListLength::Short,
+            ListStyle::Narrow => ListLength::Narrow,
+        };
+
+        match options.list_type {
+            ListType::Conjunction => IcuListFormatter::try_new_and_with_length(&locale, length),
+            ListType::Disjunction => IcuListFormatter::try_new_or_with_length(&locale, length),
+            ListType::Unit => IcuListFormatter::try_new_unit_with_length(&locale, length),
+        }
+        .map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unic_langid::langid;
+
+    fn format(lang: LanguageIdentifier, items: &[&str], options: &[(&str, &str)]) -> String {
+        let mut args = FluentArgs::new();
+        for (key, value) in options {
+            args.set(*key, *value);
+        }
+
+        let items = items
+            .iter()
+            .map(|item| FluentValue::from(item.to_string()))
+            .collect();
+        let mut list = FluentList::new(items);
+        list.options.merge(&args);
+        list.format(&IntlLangMemoizer::new(lang))
+    }
+
+    #[test]
+    fn joins_items_for_the_locale() {
+        assert_eq!(format(langid!("en"), &["A", "B", "C"], &[]), "A, B, and C");
+        assert_eq!(format(langid!("de"), &["A", "B", "C"], &[]), "A, B und C");
+        assert_eq!(format(langid!("en"), &["A", "B"], &[]), "A and B");
+        assert_eq!(format(langid!("en"), &["A"], &[]), "A");
+        assert_eq!(format(langid!("en"), &[], &[]), "");
+    }
+
+    #[test]
+    fn applies_type_and_style() {
+        let items = ["A", "B", "C"];
+        assert_eq!(
+            format(langid!("en"), &items, &[("type", "disjunction")]),
+            "A, B, or C"
+        );
+        assert_eq!(
+            format(langid!("fr"), &items, &[("type", "disjunction")]),
+            "A, B ou C"
+        );
+        assert_eq!(
+            format(langid!("en"), &items, &[("style", "short")]),
+            "A, B, & C"
+        );
+        assert_eq!(
+            format(
+                langid!("en"),
+                &items,
+                &[("type", "unit"), ("style", "narrow")]
+            ),
+            "A B C"
+        );
+        assert_eq!(
+            format(langid!("en"), &items, &[("type", "sequence")]),
+            "A, B, and C"
+        );
+    }
+
+    #[test]
+    fn formats_items_like_placeables() {
+        let intls = IntlLangMemoizer::new(langid!("de"));
+        let list = FluentList::new(vec![FluentValue::from(1234.5), FluentValue::from("x")]);
+        assert_eq!(list.format(&intls), "1.234,5 und x");
+
+        let FluentValue::Number(count) =
+            number::builtin(&[FluentValue::Custom(Box::new(list))], &FluentArgs::new())
+        else {
+            panic!("expected the item count");
+        };
+        assert_eq!(count.value, 2.0);
+    }
+}
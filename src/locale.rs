@@ -0,0 +1,379 @@
+use std::convert::Infallible;
+
+use fluent_langneg::{NegotiationStrategy, negotiate_languages};
+use intl_memoizer::{IntlLangMemoizer, Memoizable};
+use unic_langid::subtags::{Language, Region, Script, Variant};
+use unic_langid::{CharacterDirection, LanguageIdentifier};
+
+// -- Error types --
+
+#[derive(Debug, PartialEq)]
+pub enum LocaleError {
+    /// `subtag` is the first subtag that failed to parse (1-based `position`),
+    /// and `expected` describes what the parser accepts at that position.
+    InvalidLanguageIdentifier {
+        tag: String,
+        subtag: String,
+        position: usize,
+        expected: &'static str,
+    },
+    UnknownStrategy(String),
+}
+
+// -- Parsing --
+
+/// Parse a BCP-47 language identifier, reporting which subtag is invalid.
+pub fn parse_language_identifier(tag: &str) -> Result<LanguageIdentifier, LocaleError> {
+    tag.parse::<LanguageIdentifier>()
+        .map_err(|_| invalid_subtag(tag))
+}
+
+/// Walk `tag` the way the `unic-langid` parser does (language, then optional
+/// script, region, and variants) and describe the first subtag it rejects.
+fn invalid_subtag(tag: &str) -> LocaleError {
+    let subtags = tag.split(['-', '_']).collect::<Vec<_>>();
+
+    let mut expected = "language";
+    let mut culprit = 0;
+
+    if Language::from_bytes(subtags[0].as_bytes()).is_ok() {
+        // 1: script, region or variant; 2: region or variant; 3: variant.
+        let mut state = 1;
+        culprit = subtags.len();
+        for (index, subtag) in subtags.iter().enumerate().skip(1) {
+            let bytes = subtag.as_bytes();
+            state = if state == 1 && Script::from_bytes(bytes).is_ok() {
+                2
+            } else if (state < 3 && Region::from_bytes(bytes).is_ok())
+                || Variant::from_bytes(bytes).is_ok()
+            {
+                3
+            } else {
+                expected = match state {
+                    1 => "script, region, or variant",
+                    2 => "region or variant",
+                    _ => "variant",
+                };
+                culprit = index;
+                break;
+            };
+        }
+    }
+
+    LocaleError::InvalidLanguageIdentifier {
+        tag: tag.to_string(),
+        subtag: subtags
+            .get(culprit)
+            .copied()
+            .unwrap_or_default()
+            .to_string(),
+        position: culprit + 1,
+        expected,
+    }
+}
+
+/// Convert to an ICU4X locale for CLDR data lookups. `unic-langid` and ICU4X
+/// share the BCP-47 syntax; anything ICU4X rejects falls back to the root
+/// locale.
+pub fn to_icu_locale(lang_id: &LanguageIdentifier) -> icu_locid::Locale {
+    icu_locid::LanguageIdentifier::try_from_bytes(lang_id.to_string().as_bytes())
+        .unwrap_or_default()
+        .into()
+}
+
+/// The writing direction of a locale, `"ltr"` or `"rtl"`, from its script or
+/// the likely script of its language. Top-to-bottom scripts count as `"ltr"`.
+pub fn direction(lang_id: &LanguageIdentifier) -> &'static str {
+    match lang_id.character_direction() {
+        CharacterDirection::RTL => "rtl",
+        CharacterDirection::LTR | CharacterDirection::TTB => "ltr",
+    }
+}
+
+/// The locale `intls` formats for, as a BCP-47 tag. The memoizer does not
+/// expose its locale, so it is read through a memoized value.
+pub fn memoizer_tag(intls: &IntlLangMemoizer) -> String {
+    intls
+        .with_try_get::<Tag, _, _>((), |tag| tag.0.clone())
+        .unwrap_or_default()
+}
+
+struct Tag(String);
+
+impl Memoizable for Tag {
+    type Args = ();
+    type Error = Infallible;
+
+    fn construct(lang: LanguageIdentifier, _args: ()) -> Result<Self, Infallible> {
+        Ok(Self(lang.to_string()))
+    }
+}
+
+// -- Negotiation --
+
+/// A parsed language identifier that remembers the string it came from, so
+/// negotiation results can be handed back to PHP exactly as the caller spelled
+/// them (e.g. to map onto directory names).
+#[derive(Debug)]
+struct Tagged {
+    id: LanguageIdentifier,
+    original: String,
+}
+
+impl Tagged {
+    fn parse(tag: &str) -> Result<Self, LocaleError> {
+        let id = parse_language_identifier(tag)?;
+        Ok(Self {
+            id,
+            original: tag.to_string(),
+        })
+    }
+}
+
+impl AsRef<LanguageIdentifier> for Tagged {
+    fn as_ref(&self) -> &LanguageIdentifier {
+        &self.id
+    }
+}
+
+impl PartialEq for Tagged {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+pub fn parse_strategy(strategy: &str) -> Result<NegotiationStrategy, LocaleError> {
+    if strategy.eq_ignore_ascii_case("filtering") {
+        Ok(NegotiationStrategy::Filtering)
+    } else if strategy.eq_ignore_ascii_case("matching") {
+        Ok(NegotiationStrategy::Matching)
+    } else if strategy.eq_ignore_ascii_case("lookup") {
+        Ok(NegotiationStrategy::Lookup)
+    } else {
+        Err(LocaleError::UnknownStrategy(strategy.to_string()))
+    }
+}
+
+/// Negotiate `requested` locales against `available` ones.
+///
+/// Requested tags that do not parse are ignored, since they typically come
+/// from user input such as an `Accept-Language` header. Invalid `available` or
+/// `default` tags are configuration errors and are reported.
+pub fn negotiate(
+    requested: &[String],
+    available: &[String],
+    default: &str,
+    strategy: NegotiationStrategy,
+) -> Result<Vec<String>, LocaleError> {
+    let requested = requested
+        .iter()
+        .filter_map(|tag| tag.parse::<LanguageIdentifier>().ok())
+        .collect::<Vec<_>>();
+    let available = available
+        .iter()
+        .map(|tag| Tagged::parse(tag))
+        .collect::<Result<Vec<_>, _>>()?;
+    let default = Tagged::parse(default)?;
+
+    Ok(
+        negotiate_languages(&requested, &available, Some(&default), strategy)
+            .into_iter()
+            .map(|tagged| tagged.original.clone())
+            .collect(),
+    )
+}
+
+// -- Accept-Language --
+
+/// Parse an HTTP `Accept-Language` header into language tags ordered by
+/// preference.
+///
+/// Entries are sorted by descending q-value; entries with equal weight keep
+/// header order. Entries with `q=0`, an unparsable q-value, the `*` wildcard,
+/// or an invalid tag are dropped, as are repeated tags.
+pub fn parse_accept_language(header: &str) -> Vec<String> {
+    let mut entries: Vec<(f32, String)> = vec![];
+
+    for entry in header.split(',') {
+        let mut parts = entry.split(';').map(str::trim);
+        let tag = parts.next().unwrap_or_default();
+        if tag.is_empty() || tag == "*" {
+            continue;
+        }
+
+        let mut quality = Some(1.0);
+        for param in parts {
+            if let Some((key, value)) = param.split_once('=') {
+                if key.trim().eq_ignore_ascii_case("q") {
+                    quality = parse_quality(value.trim());
+                }
+            }
+        }
+
+        let Some(quality) = quality.filter(|q| *q > 0.0) else {
+            continue;
+        };
+        let Ok(lang_id) = tag.parse::<LanguageIdentifier>() else {
+            continue;
+        };
+
+        let tag = lang_id.to_string();
+        if !entries.iter().any(|(_, existing)| *existing == tag) {
+            entries.push((quality, tag));
+        }
+    }
+
+    // `sort_by` is stable, so equal weights keep their header order.
+    entries.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+    entries.into_iter().map(|(_, tag)| tag).collect()
+}
+
+fn parse_quality(value: &str) -> Option<f32> {
+    value
+        .parse::<f32>()
+        .ok()
+        .filter(|q| (0.0..=1.0).contains(q))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(tags: &[&str]) -> Vec<String> {
+        tags.iter().map(|tag| tag.to_string()).collect()
+    }
+
+    fn invalid(tag: &str) -> (String, usize, &'static str) {
+        match parse_language_identifier(tag) {
+            Err(LocaleError::InvalidLanguageIdentifier {
+                subtag,
+                position,
+                expected,
+                ..
+            }) => (subtag, position, expected),
+            other => panic!("{tag:?} should be invalid, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_language_identifier_accepts_valid_tags() {
+        let lang_id = parse_language_identifier("sr_cyrl_rs_ekavsk").unwrap();
+
+        assert_eq!(lang_id.to_string(), "sr-Cyrl-RS-ekavsk");
+    }
+
+    #[test]
+    fn parse_language_identifier_reports_the_invalid_subtag() {
+        assert_eq!(invalid("!!"), ("!!".to_string(), 1, "language"));
+        assert_eq!(invalid("en-US-!!"), ("!!".to_string(), 3, "variant"));
+        assert_eq!(
+            invalid("not-a-valid-lang!!!"),
+            ("a".to_string(), 2, "script, region, or variant")
+        );
+        assert_eq!(
+            invalid("zh-Hant-!!"),
+            ("!!".to_string(), 3, "region or variant")
+        );
+        assert_eq!(invalid("de-DE-AT"), ("AT".to_string(), 3, "variant"));
+    }
+
+    #[test]
+    fn negotiate_supports_each_strategy() {
+        let requested = strings(&["de-AT", "fr"]);
+        let available = strings(&["en", "de", "de-DE", "fr-CA", "fr"]);
+
+        assert_eq!(
+            negotiate(&requested, &available, "en", NegotiationStrategy::Filtering).unwrap(),
+            strings(&["de", "de-DE", "fr", "fr-CA", "en"])
+        );
+        assert_eq!(
+            negotiate(&requested, &available, "en", NegotiationStrategy::Matching).unwrap(),
+            strings(&["de", "fr", "en"])
+        );
+        assert_eq!(
+            negotiate(&requested, &available, "en", NegotiationStrategy::Lookup).unwrap(),
+            strings(&["de"])
+        );
+    }
+
+    #[test]
+    fn negotiate_returns_available_tags_as_given_and_falls_back_to_default() {
+        let available = strings(&["en_US", "pt_BR"]);
+
+        assert_eq!(
+            negotiate(
+                &strings(&["pt-BR"]),
+                &available,
+                "en_US",
+                NegotiationStrategy::Lookup
+            )
+            .unwrap(),
+            strings(&["pt_BR"])
+        );
+        assert_eq!(
+            negotiate(
+                &strings(&["ja", "!!"]),
+                &available,
+                "en_US",
+                NegotiationStrategy::Filtering
+            )
+            .unwrap(),
+            strings(&["en_US"])
+        );
+    }
+
+    #[test]
+    fn negotiate_reports_invalid_configuration() {
+        assert!(matches!(
+            negotiate(
+                &[],
+                &strings(&["en", "!!"]),
+                "en",
+                NegotiationStrategy::Filtering
+            ),
+            Err(LocaleError::InvalidLanguageIdentifier { tag, .. }) if tag == "!!"
+        ));
+        assert_eq!(
+            parse_strategy("closest").unwrap_err(),
+            LocaleError::UnknownStrategy("closest".to_string())
+        );
+        assert_eq!(
+            parse_strategy("Lookup").unwrap(),
+            NegotiationStrategy::Lookup
+        );
+    }
+
+    #[test]
+    fn direction_follows_the_script() {
+        let direction = |tag: &str| direction(&parse_language_identifier(tag).unwrap());
+
+        assert_eq!(direction("en"), "ltr");
+        assert_eq!(direction("ar"), "rtl");
+        assert_eq!(direction("he-IL"), "rtl");
+        assert_eq!(direction("fa"), "rtl");
+        assert_eq!(direction("az-Arab"), "rtl");
+        assert_eq!(direction("ku-Latn"), "ltr");
+        assert_eq!(direction("mn-Mong"), "ltr");
+    }
+
+    #[test]
+    fn parse_accept_language_orders_by_quality() {
+        assert_eq!(
+            parse_accept_language("fr-CH, fr;q=0.9, en;q=0.8, de;q=0.7, *;q=0.5"),
+            strings(&["fr-CH", "fr", "en", "de"])
+        );
+        assert_eq!(
+            parse_accept_language("en;q=0.5, de, pt-br;q=0.5, it;q=1.0"),
+            strings(&["de", "it", "en", "pt-BR"])
+        );
+    }
+
+    #[test]
+    fn parse_accept_language_drops_rejected_and_invalid_entries() {
+        assert_eq!(
+            parse_accept_language("en;q=0, de;q=abc, fr;q=1.5, !!, ja, ja;q=0.1, , es ; Q=0.2"),
+            strings(&["ja", "es"])
+        );
+        assert!(parse_accept_language("").is_empty());
+    }
+}
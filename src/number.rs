@@ -0,0 +1,723 @@
+use std::borrow::Cow;
+use std::str::FromStr;
+
+use fixed_decimal::{FixedDecimal, FloatPrecision, Sign};
+use fluent::types::{
+    FluentNumber, FluentNumberCurrencyDisplayStyle, FluentNumberOptions, FluentNumberStyle,
+    FluentNumberType, FluentType,
+};
+use fluent::{FluentArgs, FluentValue};
+use icu_decimal::options::{FixedDecimalFormatterOptions, GroupingStrategy};
+use icu_decimal::{DecimalError, FixedDecimalFormatter};
+use icu_experimental::dimension::provider::currency::{
+    CurrencyEssentialsV1, CurrencyEssentialsV1Marker, PatternSelection, PlaceholderValue,
+};
+use icu_experimental::dimension::provider::percent::{
+    PercentEssentialsV1, PercentEssentialsV1Marker,
+};
+use icu_experimental::provider::Baked;
+use icu_provider::{DataLocale, DataPayload, DataProvider, DataRequest};
+use intl_memoizer::{IntlLangMemoizer, Memoizable};
+use intl_pluralrules::PluralRuleType;
+use intl_pluralrules::operands::PluralOperands;
+use tinystr::TinyAsciiStr;
+use unic_langid::LanguageIdentifier;
+
+use crate::list::FluentList;
+use crate::{locale, plural};
+
+/// The largest integer magnitude an `f64` holds exactly.
+pub const MAX_SAFE_INTEGER: u64 = 1 << 53;
+
+// -- Values --
+
+/// A Fluent value for the integer `n`: a plain number when an `f64` holds it
+/// exactly, otherwise a [`FluentDecimal`].
+pub fn from_integer(n: i64) -> FluentValue<'static> {
+    if n.unsigned_abs() <= MAX_SAFE_INTEGER {
+        FluentValue::Number(FluentNumber::from(n))
+    } else {
+        FluentValue::Custom(Box::new(FluentDecimal::new(FixedDecimal::from(n))))
+    }
+}
+
+/// A Fluent value for the PHP float `n`. `minimumIntegerDigits` is set to
+/// its default of 1, which formats and selects like no option, to mark the
+/// number as a float, so it goes back to PHP as one even when integral.
+pub fn from_float(n: f64) -> FluentValue<'static> {
+    let mut number = FluentNumber::from(n);
+    number.options.minimum_integer_digits = Some(1);
+    FluentValue::Number(number)
+}
+
+/// Whether `number` came from a PHP float, see [`from_float()`].
+pub fn is_float(number: &FluentNumber) -> bool {
+    number.options.minimum_integer_digits.is_some()
+}
+
+/// A Fluent value for a decimal string such as `"-19.90"` or `"1e3"`, keeping
+/// the fraction digits it shows, like number literals in FTL. Values an `f64`
+/// holds exactly become plain numbers, so they select plural variants; others
+/// become a [`FluentDecimal`]. `None` if `source` is not a decimal number.
+pub fn parse_decimal(source: &str) -> Option<FluentValue<'static>> {
+    let mut decimal = FixedDecimal::from_str(source.trim()).ok()?;
+    if decimal.sign() == Sign::Positive {
+        decimal.set_sign(Sign::None);
+    }
+    let value: f64 = decimal.to_string().parse().ok()?;
+    let mut exact = decimal.clone();
+    exact.trim_end();
+    if FixedDecimal::try_from_f64(value, FloatPrecision::Floating).ok() == Some(exact) {
+        let mut number = FluentNumber::from(value);
+        number.options.minimum_fraction_digits = fraction_digits(&decimal);
+        Some(FluentValue::Number(number))
+    } else {
+        Some(FluentValue::Custom(Box::new(FluentDecimal::new(decimal))))
+    }
+}
+
+/// A number with more digits than an `f64` holds, such as a 64-bit ID or a
+/// long decimal string, formatted without loss of precision. Fluent only
+/// selects variants for plain numbers, so formatting substitutes
+/// [`FluentDecimal::plural_category()`] for these in select expressions.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FluentDecimal {
+    pub value: FixedDecimal,
+    pub options: FluentNumberOptions,
+}
+
+impl FluentDecimal {
+    fn new(value: FixedDecimal) -> Self {
+        let options = FluentNumberOptions {
+            minimum_fraction_digits: fraction_digits(&value),
+            ..Default::default()
+        };
+        Self { value, options }
+    }
+
+    /// The plural category the exact digits select, with the rules of the
+    /// `type` option, e.g. `"one"`. Like Fluent, only `minimumFractionDigits`
+    /// is applied to the digits first.
+    pub fn plural_category(&self, intls: &IntlLangMemoizer) -> Option<&'static str> {
+        let mut decimal = self.value.clone();
+        if let Some(digits) = self.options.minimum_fraction_digits {
+            decimal.pad_end(-(digits.min(100) as i16));
+        }
+        let rule_type = match self.options.r#type {
+            FluentNumberType::Cardinal => PluralRuleType::CARDINAL,
+            FluentNumberType::Ordinal => PluralRuleType::ORDINAL,
+        };
+        plural::category(intls, rule_type, plural_operands(&decimal)).ok()
+    }
+}
+
+/// CLDR plural operands of `decimal`, from its digits rather than an `f64`.
+/// The operands hold 64-bit integers and an `f64`, so longer digit sequences
+/// keep their last digits, which are all CLDR rules test (`i % 100`,
+/// `n % 1000000`), plus one above them when the dropped digits are not all
+/// zero, so that large values stay large.
+fn plural_operands(decimal: &FixedDecimal) -> PluralOperands {
+    let range = decimal.magnitude_range();
+    let integer: Vec<u8> = (0..=*range.end())
+        .rev()
+        .map(|magnitude| decimal.digit_at(magnitude))
+        .collect();
+    let fraction: Vec<u8> = (*range.start()..0)
+        .rev()
+        .map(|magnitude| decimal.digit_at(magnitude))
+        .collect();
+    let trimmed = match fraction.iter().rposition(|digit| *digit != 0) {
+        Some(last) => &fraction[..=last],
+        None => &[],
+    };
+
+    let fraction_value: f64 = fraction
+        .iter()
+        .rev()
+        .fold(0.0, |value, digit| (value + f64::from(*digit)) / 10.0);
+    PluralOperands {
+        n: last_digits(&integer, 15) as f64 + fraction_value,
+        i: last_digits(&integer, 18),
+        v: fraction.len(),
+        w: trimmed.len(),
+        f: last_digits(&fraction, 18),
+        t: last_digits(trimmed, 18),
+    }
+}
+
+/// The value of the last `count` of `digits`, plus `10^count` when the digits
+/// before them are not all zero.
+fn last_digits(digits: &[u8], count: usize) -> u64 {
+    let split = digits.len().saturating_sub(count);
+    let value = digits[split..]
+        .iter()
+        .fold(0, |value, digit| value * 10 + u64::from(*digit));
+    if digits[..split].iter().any(|digit| *digit != 0) {
+        value + 10u64.pow(count as u32)
+    } else {
+        value
+    }
+}
+
+/// The number of fraction digits `decimal` shows, if any.
+fn fraction_digits(decimal: &FixedDecimal) -> Option<usize> {
+    let digits = -*decimal.magnitude_range().start();
+    (digits > 0).then_some(digits as usize)
+}
+
+impl FluentType for FluentDecimal {
+    fn duplicate(&self) -> Box<dyn FluentType + Send> {
+        Box::new(self.clone())
+    }
+
+    fn as_string(&self, intls: &IntlLangMemoizer) -> Cow<'static, str> {
+        intls
+            .with_try_get::<NumberFormatter, _, _>((), |formatter| {
+                formatter.format_decimal(self.value.clone(), &self.options)
+            })
+            .ok()
+            .flatten()
+            .unwrap_or_else(|| self.value.to_string())
+            .into()
+    }
+
+    fn as_string_threadsafe(
+        &self,
+        _intls: &intl_memoizer::concurrent::IntlLangMemoizer,
+    ) -> Cow<'static, str> {
+        self.value.to_string().into()
+    }
+}
+
+/// The `NUMBER()` builtin. Like fluent-bundle's, it applies its options to a
+/// number; it also accepts exact decimals and numeric strings, which keep
+/// their digits, and lists, which count their items.
+pub fn builtin<'a>(positional: &[FluentValue<'a>], named: &FluentArgs) -> FluentValue<'a> {
+    match positional.first() {
+        Some(FluentValue::Number(number)) => {
+            let mut number = number.clone();
+            number.options.merge(named);
+            FluentValue::Number(number)
+        }
+        Some(FluentValue::Custom(value)) => {
+            let value = value.as_ref().as_any();
+            if let Some(decimal) = value.downcast_ref::<FluentDecimal>() {
+                let mut decimal = decimal.clone();
+                decimal.options.merge(named);
+                FluentValue::Custom(Box::new(decimal))
+            } else if let Some(list) = value.downcast_ref::<FluentList>() {
+                builtin(&[FluentValue::Number(list.count())], named)
+            } else {
+                FluentValue::Error
+            }
+        }
+        Some(FluentValue::String(source)) => match parse_decimal(source) {
+            Some(number) => builtin(&[number], named),
+            None => FluentValue::Error,
+        },
+        _ => FluentValue::Error,
+    }
+}
+
+// -- Formatter hook --
+
+/// Bundle formatter that renders numbers with the bundle locale's separators
+/// and digits. Other values fall back to Fluent's default formatting.
+pub fn format_value(value: &FluentValue, intls: &IntlLangMemoizer) -> Option<String> {
+    let FluentValue::Number(number) = value else {
+        return None;
+    };
+
+    intls
+        .with_try_get::<NumberFormatter, _, _>((), |formatter| formatter.format(number))
+        .ok()
+        .flatten()
+}
+
+// -- Number formatter --
+
+/// CLDR decimal symbols and percent and currency patterns for one locale,
+/// memoized per bundle.
+pub struct NumberFormatter {
+    grouped: FixedDecimalFormatter,
+    ungrouped: FixedDecimalFormatter,
+    /// Text before and after the digits of a negative number, e.g. `("-", "")`.
+    minus: (String, String),
+    /// Text before and after the digits of a percentage, e.g. `("", "%")`.
+    percent: (String, String),
+    currency: DataPayload<CurrencyEssentialsV1Marker>,
+}
+
+impl Memoizable for NumberFormatter {
+    type Args = ();
+    type Error = DecimalError;
+
+    fn construct(lang: LanguageIdentifier, _args: ()) -> Result<Self, DecimalError> {
+        let locale = locale::to_icu_locale(&lang);
+
+        let grouped = FixedDecimalFormatter::try_new(
+            &(&locale).into(),
+            FixedDecimalFormatterOptions::from(GroupingStrategy::Auto),
+        )?;
+        let ungrouped = FixedDecimalFormatter::try_new(
+            &(&locale).into(),
+            FixedDecimalFormatterOptions::from(GroupingStrategy::Never),
+        )?;
+
+        let one = grouped.format_to_string(&FixedDecimal::from(1));
+        let minus = grouped.format_to_string(&FixedDecimal::from(-1));
+        let minus = match minus.split_once(&one) {
+            Some((prefix, suffix)) => (prefix.to_string(), suffix.to_string()),
+            None => ("-".to_string(), String::new()),
+        };
+
+        let data_locale = DataLocale::from(&locale);
+        let request = DataRequest {
+            locale: &data_locale,
+            metadata: Default::default(),
+        };
+        let percent: DataPayload<PercentEssentialsV1Marker> = Baked
+            .load(request)
+            .and_then(|response| response.take_payload())
+            .map_err(DecimalError::Data)?;
+        let currency = Baked
+            .load(request)
+            .and_then(|response| response.take_payload())
+            .map_err(DecimalError::Data)?;
+
+        Ok(Self {
+            grouped,
+            ungrouped,
+            minus,
+            percent: percent_affixes(percent.get()),
+            currency,
+        })
+    }
+}
+
+impl NumberFormatter {
+    /// Format `number` according to its `NUMBER()` options. Returns `None` for
+    /// values it cannot represent (NaN, infinity, or a currency style without
+    /// a currency), which Fluent then formats itself.
+    pub fn format(&self, number: &FluentNumber) -> Option<String> {
+        let decimal = FixedDecimal::try_from_f64(number.value, FloatPrecision::Floating).ok()?;
+        self.format_decimal(decimal, &number.options)
+    }
+
+    /// Format an exact `decimal` according to `NUMBER()` options.
+    pub fn format_decimal(
+        &self,
+        mut decimal: FixedDecimal,
+        options: &FluentNumberOptions,
+    ) -> Option<String> {
+        let fraction_digits = match options.style {
+            FluentNumberStyle::Decimal => (0, 3),
+            FluentNumberStyle::Percent => {
+                decimal.multiply_pow10(2);
+                decimal.trim_start();
+                (0, 0)
+            }
+            FluentNumberStyle::Currency => {
+                let digits = currency_digits(options.currency.as_deref()?);
+                (digits, digits)
+            }
+        };
+
+        round(&mut decimal, options, fraction_digits);
+
+        let negative = decimal.sign() == Sign::Negative && !decimal.is_zero();
+        decimal.set_sign(Sign::None);
+
+        let digits = if options.use_grouping {
+            self.grouped.format_to_string(&decimal)
+        } else {
+            self.ungrouped.format_to_string(&decimal)
+        };
+
+        let unsigned = match options.style {
+            FluentNumberStyle::Decimal => digits,
+            FluentNumberStyle::Percent => format!("{}{}{}", self.percent.0, digits, self.percent.1),
+            FluentNumberStyle::Currency => currency(
+                self.currency.get(),
+                &digits,
+                options.currency.as_deref()?,
+                options.currency_display,
+            )?,
+        };
+
+        if negative {
+            Some(format!("{}{}{}", self.minus.0, unsigned, self.minus.1))
+        } else {
+            Some(unsigned)
+        }
+    }
+}
+
+/// Round and pad `decimal` to the digit options, following
+/// `Intl.NumberFormat`: significant digits, when given, take precedence over
+/// fraction digits, whose defaults depend on the style.
+fn round(
+    decimal: &mut FixedDecimal,
+    options: &FluentNumberOptions,
+    (default_min, default_max): (usize, usize),
+) {
+    if options.minimum_significant_digits.is_some() || options.maximum_significant_digits.is_some()
+    {
+        let min = options.minimum_significant_digits.unwrap_or(1).clamp(1, 21) as i16;
+        let max = options
+            .maximum_significant_digits
+            .unwrap_or(21)
+            .clamp(min as usize, 21) as i16;
+
+        decimal.half_expand(decimal.nonzero_magnitude_start() - max + 1);
+        decimal.trim_end();
+        decimal.pad_end(decimal.nonzero_magnitude_start() - min + 1);
+    } else {
+        let (min, max) = match (
+            options.minimum_fraction_digits,
+            options.maximum_fraction_digits,
+        ) {
+            (Some(min), Some(max)) => (min, max.max(min)),
+            (Some(min), None) => (min, default_max.max(min)),
+            (None, Some(max)) => (default_min.min(max), max),
+            (None, None) => (default_min, default_max),
+        };
+
+        decimal.half_expand(-(max.min(100) as i16));
+        decimal.trim_end();
+        decimal.pad_end(-(min.min(100) as i16));
+    }
+
+    if let Some(min) = options.minimum_integer_digits {
+        decimal.pad_start(min.clamp(1, 21) as i16);
+    }
+}
+
+// -- Locale patterns --
+
+/// The text around the digits in the CLDR percent pattern, such as `#,##0%`
+/// or `%#,##0`, with the locale's percent sign.
+fn percent_affixes(percent: &PercentEssentialsV1) -> (String, String) {
+    let pattern = percent.standard.as_ref();
+    let start = usize::from(percent.number_index).min(pattern.len());
+    let end = pattern[start..]
+        .find(|c| !matches!(c, '#' | '0' | ',' | '.'))
+        .map_or(pattern.len(), |end| start + end);
+    let sign = |text: &str| text.replace('%', &percent.percent_sign_symbol);
+
+    (sign(&pattern[..start]), sign(&pattern[end..]))
+}
+
+/// `digits` in the CLDR currency pattern of the locale, with the currency's
+/// symbol in the locale, or its ISO code. Codes and currencies without a
+/// symbol use the pattern for alphabetic signs, which spaces them from the
+/// digits (`CHF 5.00`). `None` if `code` is not a three-letter code.
+fn currency(
+    essentials: &CurrencyEssentialsV1,
+    digits: &str,
+    code: &str,
+    display: FluentNumberCurrencyDisplayStyle,
+) -> Option<String> {
+    let code = TinyAsciiStr::<3>::from_str(code).ok()?.to_ascii_uppercase();
+    let (sign, selection) = match display {
+        FluentNumberCurrencyDisplayStyle::Symbol => {
+            let config = essentials
+                .pattern_config_map
+                .get_copied(&code.to_unvalidated())
+                .unwrap_or(essentials.default_pattern_config);
+            let sign = match config.short_placeholder_value {
+                Some(PlaceholderValue::Index(index)) => {
+                    essentials.placeholders.get(index.into())?
+                }
+                Some(PlaceholderValue::ISO) | None => code.as_str(),
+            };
+            (sign, config.short_pattern_selection)
+        }
+        _ => (
+            code.as_str(),
+            essentials.default_pattern_config.short_pattern_selection,
+        ),
+    };
+
+    let pattern = match selection {
+        PatternSelection::Standard => essentials.standard_pattern.as_ref(),
+        PatternSelection::StandardAlphaNextToNumber => essentials
+            .standard_alpha_next_to_number_pattern
+            .as_ref()
+            .or(essentials.standard_pattern.as_ref()),
+    }?;
+    Some(pattern.interpolate_to_string((digits, sign)))
+}
+
+/// ISO 4217 minor units of currencies that do not use 2.
+fn currency_digits(code: &str) -> usize {
+    match code.to_ascii_uppercase().as_str() {
+        "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF" | "UGX"
+        | "UYI" | "VND" | "VUV" | "XAF" | "XOF" | "XPF" => 0,
+        "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+        "CLF" | "UYW" => 4,
+        _ => 2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fluent::FluentArgs;
+    use unic_langid::langid;
+
+    fn format(lang: LanguageIdentifier, value: f64, options: &[(&str, FluentValue)]) -> String {
+        let mut args = FluentArgs::new();
+        for (key, value) in options {
+            args.set(*key, value.clone());
+        }
+
+        let mut number = FluentNumber::from(value);
+        number.options.merge(&args);
+
+        let intls = IntlLangMemoizer::new(lang);
+        format_value(&FluentValue::Number(number), &intls).unwrap()
+    }
+
+    #[test]
+    fn formats_decimals_with_locale_symbols() {
+        assert_eq!(format(langid!("en"), 1234567.891, &[]), "1,234,567.891");
+        assert_eq!(format(langid!("de"), 1234567.891, &[]), "1.234.567,891");
+        assert_eq!(format(langid!("en"), -1234.5, &[]), "-1,234.5");
+        assert_eq!(format(langid!("en-IN"), 1234567.0, &[]), "12,34,567");
+        assert_eq!(format(langid!("ar-EG"), 12.5, &[]), "١٢٫٥");
+        assert_eq!(format(langid!("en"), 2.0 / 3.0, &[]), "0.667");
+    }
+
+    #[test]
+    fn honors_digit_options() {
+        let n = |n: f64| FluentValue::from(n);
+        assert_eq!(
+            format(langid!("en"), 1.5, &[("minimumFractionDigits", n(2.0))]),
+            "1.50"
+        );
+        assert_eq!(
+            format(langid!("en"), 1.23456, &[("maximumFractionDigits", n(2.0))]),
+            "1.23"
+        );
+        assert_eq!(
+            format(langid!("en"), 1234.5, &[("maximumFractionDigits", n(0.0))]),
+            "1,235"
+        );
+        assert_eq!(
+            format(langid!("en"), 7.0, &[("minimumIntegerDigits", n(3.0))]),
+            "007"
+        );
+        assert_eq!(
+            format(
+                langid!("en"),
+                123456.0,
+                &[("maximumSignificantDigits", n(2.0))]
+            ),
+            "120,000"
+        );
+        assert_eq!(
+            format(langid!("en"), 0.5, &[("minimumSignificantDigits", n(3.0))]),
+            "0.500"
+        );
+        assert_eq!(
+            format(langid!("en"), 12345.0, &[("useGrouping", "false".into())]),
+            "12345"
+        );
+    }
+
+    #[test]
+    fn formats_percentages() {
+        let percent = [("style", FluentValue::from("percent"))];
+        assert_eq!(format(langid!("en"), 0.256, &percent), "26%");
+        assert_eq!(format(langid!("de"), 0.256, &percent), "26\u{a0}%");
+        assert_eq!(format(langid!("fr"), 0.256, &percent), "26\u{a0}%");
+        assert_eq!(format(langid!("de-CH"), 0.256, &percent), "26%");
+        assert_eq!(format(langid!("tr"), 0.256, &percent), "%26");
+        assert_eq!(format(langid!("en-IN"), 123.45, &percent), "12,345%");
+        assert_eq!(format(langid!("en"), -0.5, &percent), "-50%");
+    }
+
+    #[test]
+    fn formats_currencies() {
+        let currency = |code: &str| {
+            [
+                ("style", FluentValue::from("currency")),
+                ("currency", FluentValue::from(code.to_string())),
+            ]
+        };
+        assert_eq!(format(langid!("en"), 1234.5, &currency("USD")), "$1,234.50");
+        assert_eq!(
+            format(langid!("de"), 1234.5, &currency("EUR")),
+            "1.234,50\u{a0}€"
+        );
+        assert_eq!(format(langid!("en"), 1234.5, &currency("JPY")), "¥1,235");
+        assert_eq!(
+            format(langid!("en"), 1234.5, &currency("CHF")),
+            "CHF\u{a0}1,234.50"
+        );
+        assert_eq!(format(langid!("en"), -5.0, &currency("USD")), "-$5.00");
+
+        assert_eq!(format(langid!("en-CA"), 5.0, &currency("USD")), "US$5.00");
+        assert_eq!(format(langid!("en-CA"), 5.0, &currency("CAD")), "$5.00");
+        assert_eq!(
+            format(langid!("fr-CA"), 5.0, &currency("USD")),
+            "5,00\u{a0}$\u{a0}US"
+        );
+        assert_eq!(
+            format(langid!("de-CH"), 1234.5, &currency("CHF")),
+            "CHF\u{a0}1’234.50"
+        );
+        assert_eq!(
+            format(langid!("en-IN"), 1234567.0, &currency("INR")),
+            "₹12,34,567.00"
+        );
+        assert_eq!(
+            format(langid!("en"), 5.0, &currency("KWD")),
+            "KWD\u{a0}5.000"
+        );
+        assert_eq!(
+            format(langid!("en"), 5.0, &currency("xyz")),
+            "XYZ\u{a0}5.00"
+        );
+
+        let mut code = currency("EUR").to_vec();
+        code.push(("currencyDisplay", FluentValue::from("code")));
+        assert_eq!(format(langid!("en"), 5.0, &code), "EUR\u{a0}5.00");
+    }
+
+    #[test]
+    fn keeps_exact_digits() {
+        let intls = IntlLangMemoizer::new(langid!("en"));
+        let show = |value: FluentValue| match value {
+            FluentValue::Custom(custom) => custom.as_string(&intls).into_owned(),
+            value => format_value(&value, &intls).unwrap(),
+        };
+
+        assert_eq!(show(parse_decimal("19.90").unwrap()), "19.90");
+        assert_eq!(show(parse_decimal("-0.50").unwrap()), "-0.50");
+        assert_eq!(show(parse_decimal(" 1e3 ").unwrap()), "1,000");
+        assert_eq!(
+            show(parse_decimal("12345678901234567890.5").unwrap()),
+            "12,345,678,901,234,567,890.5"
+        );
+        assert_eq!(show(from_integer(i64::MAX)), "9,223,372,036,854,775,807");
+        assert_eq!(show(from_integer(-42)), "-42");
+        assert!(parse_decimal("12 apples").is_none());
+
+        // Exact decimals become plain numbers, which select plural variants.
+        let FluentValue::Number(number) = parse_decimal("1.0").unwrap() else {
+            panic!("expected a number");
+        };
+        assert_eq!(number.options.minimum_fraction_digits, Some(1));
+        assert!(matches!(from_integer(1 << 60), FluentValue::Custom(_)));
+    }
+
+    #[test]
+    fn marks_floats_without_changing_them() {
+        let intls = IntlLangMemoizer::new(langid!("en"));
+        let currency = {
+            let mut args = FluentArgs::new();
+            args.set("style", "currency");
+            args.set("currency", "EUR");
+            args
+        };
+        let FluentValue::Number(float) = builtin(&[from_float(1234.0)], &currency) else {
+            panic!("expected a number");
+        };
+        let FluentValue::Number(integer) = builtin(&[from_integer(1234)], &currency) else {
+            panic!("expected a number");
+        };
+
+        assert!(is_float(&float));
+        assert!(!is_float(&integer));
+        assert_eq!(
+            format_value(&FluentValue::Number(float.clone()), &intls),
+            format_value(&FluentValue::Number(integer.clone()), &intls)
+        );
+        assert_eq!(PluralOperands::from(&float), PluralOperands::from(&integer));
+    }
+
+    #[test]
+    fn takes_plural_operands_from_exact_digits() {
+        let operands = |source: &str| plural_operands(&FixedDecimal::from_str(source).unwrap());
+
+        assert_eq!(operands("1"), PluralOperands::try_from("1").unwrap());
+        assert_eq!(operands("-1.50"), PluralOperands::try_from("1.50").unwrap());
+        let large = operands("9007199254740993");
+        assert_eq!(
+            (large.i, large.n),
+            (9_007_199_254_740_993, 1_007_199_254_740_993.0)
+        );
+        let huge = operands("123456789012345678901234567890.0100");
+        assert_eq!(huge.i, 1_345_678_901_234_567_890);
+        assert_eq!((huge.v, huge.w, huge.f, huge.t), (4, 2, 100, 1));
+    }
+
+    #[test]
+    fn selects_plural_categories_of_exact_digits() {
+        let category = |lang: LanguageIdentifier, value: FluentValue, options: &FluentArgs| {
+            let FluentValue::Custom(decimal) = builtin(&[value], options) else {
+                panic!("expected a decimal");
+            };
+            let decimal = decimal.as_any().downcast_ref::<FluentDecimal>().unwrap();
+            decimal
+                .plural_category(&IntlLangMemoizer::new(lang))
+                .unwrap()
+        };
+        let none = FluentArgs::new();
+
+        // 10^16 + 1 is 10^16 as an `f64`, which selects `many`.
+        assert_eq!(
+            category(langid!("ru"), from_integer(10_000_000_000_000_001), &none),
+            "one"
+        );
+        assert_eq!(
+            category(langid!("en"), from_integer(1 << 60), &none),
+            "other"
+        );
+        assert_eq!(
+            category(langid!("en"), "10000000000000000000001".into(), &none),
+            "other"
+        );
+
+        let mut ordinal = FluentArgs::new();
+        ordinal.set("type", "ordinal");
+        assert_eq!(
+            category(langid!("en"), from_integer(9_007_199_254_740_993), &ordinal),
+            "few"
+        );
+    }
+
+    #[test]
+    fn number_builtin_accepts_decimals() {
+        let intls = IntlLangMemoizer::new(langid!("en"));
+        let mut options = FluentArgs::new();
+        options.set("style", "currency");
+        options.set("currency", "USD");
+
+        let price = builtin(&[FluentValue::from("1234.50")], &options);
+        assert_eq!(format_value(&price, &intls).unwrap(), "$1,234.50");
+
+        let FluentValue::Custom(id) = builtin(&[from_integer(i64::MIN)], &FluentArgs::new()) else {
+            panic!("expected a decimal");
+        };
+        assert_eq!(id.as_string(&intls), "-9,223,372,036,854,775,808");
+
+        let text = builtin(&[FluentValue::from("many")], &FluentArgs::new());
+        assert!(matches!(text, FluentValue::Error));
+    }
+
+    #[test]
+    fn leaves_unrepresentable_values_to_fluent() {
+        let intls = IntlLangMemoizer::new(langid!("en"));
+        let nan = FluentValue::Number(FluentNumber::from(f64::NAN));
+        assert_eq!(format_value(&nan, &intls), None);
+
+        let mut number = FluentNumber::from(5.0);
+        number.options.style = FluentNumberStyle::Currency;
+        assert_eq!(format_value(&FluentValue::Number(number), &intls), None);
+
+        assert_eq!(format_value(&FluentValue::from("text"), &intls), None);
+    }
+}
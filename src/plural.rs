@@ -0,0 +1,154 @@
+use fluent_langneg::{NegotiationStrategy, negotiate_languages};
+use intl_memoizer::{IntlLangMemoizer, Memoizable};
+use intl_pluralrules::operands::PluralOperands;
+use intl_pluralrules::{PluralCategory, PluralRuleType, PluralRules as IntlPluralRules};
+use unic_langid::LanguageIdentifier;
+
+/// Every category, in CLDR order.
+const CATEGORIES: [PluralCategory; 6] = [
+    PluralCategory::ZERO,
+    PluralCategory::ONE,
+    PluralCategory::TWO,
+    PluralCategory::FEW,
+    PluralCategory::MANY,
+    PluralCategory::OTHER,
+];
+
+/// CLDR plural rules for a bundle's locale, picked the way fluent-bundle picks
+/// them for select expressions (falling back to `en`), so the categories
+/// reported here always agree with the variants `formatPattern()` selects.
+pub struct PluralRules(IntlPluralRules);
+
+impl Memoizable for PluralRules {
+    type Args = (PluralRuleType,);
+    type Error = &'static str;
+
+    fn construct(lang: LanguageIdentifier, args: Self::Args) -> Result<Self, Self::Error> {
+        let default_lang: LanguageIdentifier = "en".parse().unwrap();
+        let pr_lang = negotiate_languages(
+            &[lang],
+            &IntlPluralRules::get_locales(args.0),
+            Some(&default_lang),
+            NegotiationStrategy::Lookup,
+        )[0]
+        .clone();
+        Ok(Self(IntlPluralRules::create(pr_lang, args.0)?))
+    }
+}
+
+pub fn parse_type(rule_type: &str) -> Option<PluralRuleType> {
+    match rule_type {
+        "cardinal" => Some(PluralRuleType::CARDINAL),
+        "ordinal" => Some(PluralRuleType::ORDINAL),
+        _ => None,
+    }
+}
+
+/// The category `number` selects, e.g. `"one"`.
+pub fn category(
+    intls: &IntlLangMemoizer,
+    rule_type: PluralRuleType,
+    number: PluralOperands,
+) -> Result<&'static str, &'static str> {
+    intls.with_try_get::<PluralRules, _, _>((rule_type,), |rules| {
+        rules.0.select(number).map(|category| name(&category))
+    })?
+}
+
+/// The categories the locale uses, in CLDR order. The rules do not list
+/// their categories, so they are found by selecting over sample numbers that
+/// cover every condition in CLDR: integers up to 1000, a million, and
+/// decimals with one and two fraction digits.
+pub fn categories(
+    intls: &IntlLangMemoizer,
+    rule_type: PluralRuleType,
+) -> Result<Vec<&'static str>, &'static str> {
+    intls.with_try_get::<PluralRules, _, _>((rule_type,), |rules| {
+        let integers = (0..=1000).chain([1_000_000]).map(|n: u64| n.to_string());
+        let decimals = (0..=20).flat_map(|i| {
+            (0..10)
+                .map(move |f| format!("{}.{}", i, f))
+                .chain((0..100).map(move |f| format!("{}.{:02}", i, f)))
+        });
+
+        let mut found = [false; CATEGORIES.len()];
+        for sample in integers.chain(decimals) {
+            let category = rules.0.select(sample.as_str())?;
+            if let Some(index) = CATEGORIES.iter().position(|c| *c == category) {
+                found[index] = true;
+            }
+        }
+
+        Ok(CATEGORIES
+            .iter()
+            .zip(found)
+            .filter(|(_, found)| *found)
+            .map(|(category, _)| name(category))
+            .collect())
+    })?
+}
+
+fn name(category: &PluralCategory) -> &'static str {
+    match category {
+        PluralCategory::ZERO => "zero",
+        PluralCategory::ONE => "one",
+        PluralCategory::TWO => "two",
+        PluralCategory::FEW => "few",
+        PluralCategory::MANY => "many",
+        PluralCategory::OTHER => "other",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unic_langid::langid;
+
+    fn select(lang: LanguageIdentifier, rule_type: PluralRuleType, number: &str) -> &'static str {
+        let intls = IntlLangMemoizer::new(lang);
+        category(&intls, rule_type, number.try_into().unwrap()).unwrap()
+    }
+
+    fn list(lang: LanguageIdentifier, rule_type: PluralRuleType) -> Vec<&'static str> {
+        categories(&IntlLangMemoizer::new(lang), rule_type).unwrap()
+    }
+
+    #[test]
+    fn selects_cardinal_and_ordinal_categories() {
+        use PluralRuleType::{CARDINAL, ORDINAL};
+
+        assert_eq!(select(langid!("en"), CARDINAL, "1"), "one");
+        assert_eq!(select(langid!("en"), CARDINAL, "1.0"), "other");
+        assert_eq!(select(langid!("en"), ORDINAL, "22"), "two");
+        assert_eq!(select(langid!("ru"), CARDINAL, "5"), "many");
+        assert_eq!(select(langid!("ar"), CARDINAL, "0"), "zero");
+        // Locales without rules use English ones, like select expressions.
+        assert_eq!(select(langid!("tlh"), CARDINAL, "1"), "one");
+    }
+
+    #[test]
+    fn lists_the_categories_a_locale_uses() {
+        use PluralRuleType::{CARDINAL, ORDINAL};
+
+        assert_eq!(list(langid!("en"), CARDINAL), ["one", "other"]);
+        assert_eq!(list(langid!("en"), ORDINAL), ["one", "two", "few", "other"]);
+        assert_eq!(list(langid!("fr"), CARDINAL), ["one", "other"]);
+        assert_eq!(
+            list(langid!("ru"), CARDINAL),
+            ["one", "few", "many", "other"]
+        );
+        assert_eq!(list(langid!("lv"), CARDINAL), ["zero", "one", "other"]);
+        assert_eq!(
+            list(langid!("ar"), CARDINAL),
+            ["zero", "one", "two", "few", "many", "other"]
+        );
+        assert_eq!(list(langid!("ja"), CARDINAL), ["other"]);
+    }
+
+    #[test]
+    fn parses_rule_types() {
+        assert!(parse_type("cardinal") == Some(PluralRuleType::CARDINAL));
+        assert!(parse_type("ordinal") == Some(PluralRuleType::ORDINAL));
+        assert!(parse_type("Ordinal").is_none());
+    }
+}
@@ -0,0 +1,116 @@
+use std::borrow::Cow;
+
+// -- Strategies --
+
+/// A pseudo-localization strategy, applied to the literal text of patterns
+/// through the bundle's transform hook. Placeables are left untouched.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Strategy {
+    /// Accented Latin letters, with vowels doubled for about 30% elongation:
+    /// `Accented` becomes `Ȧȧƈƈḗḗƞŧḗḗḓ`.
+    Accented,
+    /// Upside-down Latin letters, forced right-to-left with U+202E and
+    /// U+202C, to check layouts for right-to-left locales.
+    Bidi,
+}
+
+impl Strategy {
+    pub fn parse(name: &str) -> Option<Self> {
+        if name.eq_ignore_ascii_case("accented") {
+            Some(Self::Accented)
+        } else if name.eq_ignore_ascii_case("bidi") {
+            Some(Self::Bidi)
+        } else {
+            None
+        }
+    }
+
+    /// The function to pass to `FluentBundle::set_transform()`.
+    pub fn transform(self) -> fn(&str) -> Cow<'_, str> {
+        match self {
+            Self::Accented => accented,
+            Self::Bidi => bidi,
+        }
+    }
+}
+
+// -- Transforms --
+
+const ACCENTED_SMALL: [char; 26] = [
+    'ȧ', 'ƀ', 'ƈ', 'ḓ', 'ḗ', 'ƒ', 'ɠ', 'ħ', 'ī', 'ĵ', 'ķ', 'ŀ', 'ḿ', 'ƞ', 'ǿ', 'ƥ', 'ɋ', 'ř', 'ş',
+    'ŧ', 'ŭ', 'ṽ', 'ẇ', 'ẋ', 'ẏ', 'ẑ',
+];
+const ACCENTED_CAPITAL: [char; 26] = [
+    'Ȧ', 'Ɓ', 'Ƈ', 'Ḓ', 'Ḗ', 'Ƒ', 'Ɠ', 'Ħ', 'Ī', 'Ĵ', 'Ķ', 'Ŀ', 'Ḿ', 'Ƞ', 'Ǿ', 'Ƥ', 'Ɋ', 'Ř', 'Ş',
+    'Ŧ', 'Ŭ', 'Ṽ', 'Ẇ', 'Ẋ', 'Ẏ', 'Ẑ',
+];
+const FLIPPED_SMALL: [char; 26] = [
+    'ɐ', 'q', 'ɔ', 'p', 'ǝ', 'ɟ', 'ƃ', 'ɥ', 'ı', 'ɾ', 'ʞ', 'ʅ', 'ɯ', 'u', 'o', 'd', 'b', 'ɹ', 's',
+    'ʇ', 'n', 'ʌ', 'ʍ', 'x', 'ʎ', 'z',
+];
+const FLIPPED_CAPITAL: [char; 26] = [
+    '∀', 'Ԑ', 'Ↄ', 'ᗡ', 'Ǝ', 'Ⅎ', '⅁', 'H', 'I', 'ſ', 'Ӽ', '⅂', 'W', 'N', 'O', 'Ԁ', 'Ò', 'ᴚ', 'S',
+    '⊥', '∩', 'Ʌ', 'M', 'X', 'ʎ', 'Z',
+];
+
+fn accented(text: &str) -> Cow<'_, str> {
+    let mut result = String::with_capacity(text.len() * 3);
+    for ch in text.chars() {
+        match ch {
+            'a'..='z' => {
+                let mapped = ACCENTED_SMALL[ch as usize - 'a' as usize];
+                result.push(mapped);
+                if matches!(ch, 'a' | 'e' | 'o' | 'u') {
+                    result.push(mapped);
+                }
+            }
+            'A'..='Z' => {
+                let index = ch as usize - 'A' as usize;
+                result.push(ACCENTED_CAPITAL[index]);
+                if matches!(ch, 'A' | 'E' | 'O' | 'U') {
+                    result.push(ACCENTED_SMALL[index]);
+                }
+            }
+            _ => result.push(ch),
+        }
+    }
+    result.into()
+}
+
+fn bidi(text: &str) -> Cow<'_, str> {
+    let mut result = String::with_capacity(text.len() * 3 + 6);
+    result.push('\u{202E}');
+    for ch in text.chars() {
+        result.push(match ch {
+            'a'..='z' => FLIPPED_SMALL[ch as usize - 'a' as usize],
+            'A'..='Z' => FLIPPED_CAPITAL[ch as usize - 'A' as usize],
+            _ => ch,
+        });
+    }
+    result.push('\u{202C}');
+    result.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accented_maps_letters_and_elongates_vowels() {
+        assert_eq!(accented("Accented"), "Ȧȧƈƈḗḗƞŧḗḗḓ");
+        assert_eq!(accented("Open 3 files!"), "Ǿǿƥḗḗƞ 3 ƒīŀḗḗş!");
+        assert_eq!(accented("Zoë"), "Ẑǿǿë");
+    }
+
+    #[test]
+    fn bidi_flips_letters_and_forces_right_to_left() {
+        assert_eq!(bidi("Hello, World"), "\u{202E}Hǝʅʅo, Moɹʅp\u{202C}");
+    }
+
+    #[test]
+    fn parse_ignores_case() {
+        assert_eq!(Strategy::parse("Accented"), Some(Strategy::Accented));
+        assert_eq!(Strategy::parse("BIDI"), Some(Strategy::Bidi));
+        assert_eq!(Strategy::parse("flipped"), None);
+    }
+}
@@ -0,0 +1,242 @@
+use fluent::{FluentArgs, FluentValue};
+use fluent_syntax::ast::{CallArguments, Expression, InlineExpression, Pattern, PatternElement};
+
+use crate::number;
+
+/// A select-expression selector on a variable, which formatting may replace
+/// with a value Fluent selects on.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Selector<'p, 's> {
+    /// `{ $var -> ... }`
+    Variable(&'s str),
+    /// `{ NUMBER($var, type: "ordinal") -> ... }`
+    Number(&'s str, &'p CallArguments<&'s str>),
+}
+
+impl<'p, 's> Selector<'p, 's> {
+    pub fn variable(&self) -> &'s str {
+        match self {
+            Self::Variable(name) | Self::Number(name, _) => name,
+        }
+    }
+
+    /// The selector's value with `args`, or `None` if its variable is unset.
+    /// FTL only allows literals as named arguments, so `NUMBER()` is applied
+    /// here as the bundle would apply it.
+    pub fn value<'a>(&self, args: &FluentArgs<'a>) -> Option<FluentValue<'a>> {
+        let value = args.get(self.variable().to_string())?;
+        let Self::Number(_, arguments) = self else {
+            return Some(value.clone());
+        };
+
+        let mut named = FluentArgs::with_capacity(arguments.named.len());
+        for argument in &arguments.named {
+            match argument.value {
+                InlineExpression::StringLiteral { value } => named.set(argument.name.name, value),
+                InlineExpression::NumberLiteral { value } => {
+                    named.set(argument.name.name, FluentValue::try_number(value))
+                }
+                _ => {}
+            }
+        }
+        Some(number::builtin(std::slice::from_ref(value), &named))
+    }
+
+    fn from_expression(expression: &'p InlineExpression<&'s str>) -> Option<Self> {
+        match expression {
+            InlineExpression::VariableReference { id } => Some(Self::Variable(id.name)),
+            InlineExpression::FunctionReference { id, arguments } if id.name == "NUMBER" => {
+                match arguments.positional.first() {
+                    Some(InlineExpression::VariableReference { id }) => {
+                        Some(Self::Number(id.name, arguments))
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The selectors on variables that `pattern` uses, at any depth, without
+/// duplicates.
+pub fn selectors<'p, 's>(pattern: &'p Pattern<&'s str>) -> Vec<Selector<'p, 's>> {
+    let mut selectors = Vec::new();
+    visit_pattern(pattern, &mut |selector| {
+        if !selectors.contains(&selector) {
+            selectors.push(selector);
+        }
+    });
+    selectors
+}
+
+/// A copy of `pattern` whose selectors read the variable `replace(selector)`
+/// instead, where it returns a name. Placeables that display the variable
+/// are unchanged.
+pub fn replace_selectors<'s>(
+    pattern: &Pattern<&'s str>,
+    replace: &impl Fn(&Selector<'_, 's>) -> Option<&'s str>,
+) -> Pattern<&'s str> {
+    let mut pattern = pattern.clone();
+    replace_pattern(&mut pattern, replace);
+    pattern
+}
+
+fn visit_pattern<'p, 's>(pattern: &'p Pattern<&'s str>, found: &mut impl FnMut(Selector<'p, 's>)) {
+    for element in &pattern.elements {
+        if let PatternElement::Placeable { expression } = element {
+            visit_expression(expression, found);
+        }
+    }
+}
+
+fn visit_expression<'p, 's>(
+    expression: &'p Expression<&'s str>,
+    found: &mut impl FnMut(Selector<'p, 's>),
+) {
+    match expression {
+        Expression::Select { selector, variants } => {
+            if let InlineExpression::Placeable { expression } = selector {
+                visit_expression(expression, found);
+            } else if let Some(selector) = Selector::from_expression(selector) {
+                found(selector);
+            }
+            for variant in variants {
+                visit_pattern(&variant.value, found);
+            }
+        }
+        Expression::Inline(InlineExpression::Placeable { expression }) => {
+            visit_expression(expression, found)
+        }
+        Expression::Inline(_) => {}
+    }
+}
+
+fn replace_pattern<'s>(
+    pattern: &mut Pattern<&'s str>,
+    replace: &impl Fn(&Selector<'_, 's>) -> Option<&'s str>,
+) {
+    for element in &mut pattern.elements {
+        if let PatternElement::Placeable { expression } = element {
+            replace_expression(expression, replace);
+        }
+    }
+}
+
+fn replace_expression<'s>(
+    expression: &mut Expression<&'s str>,
+    replace: &impl Fn(&Selector<'_, 's>) -> Option<&'s str>,
+) {
+    match expression {
+        Expression::Select { selector, variants } => {
+            if let InlineExpression::Placeable { expression } = selector {
+                replace_expression(expression, replace);
+            } else if let Some(name) = Selector::from_expression(selector)
+                .as_ref()
+                .and_then(replace)
+            {
+                *selector = InlineExpression::VariableReference {
+                    id: fluent_syntax::ast::Identifier { name },
+                };
+            }
+            for variant in variants {
+                replace_pattern(&mut variant.value, replace);
+            }
+        }
+        Expression::Inline(InlineExpression::Placeable { expression }) => {
+            replace_expression(expression, replace)
+        }
+        Expression::Inline(_) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fluent_syntax::ast::Entry;
+    use fluent_syntax::parser::parse;
+
+    fn with_pattern(source: &str, test: impl FnOnce(&Pattern<&str>)) {
+        let resource = parse(source).unwrap();
+        let Some(Entry::Message(message)) = resource.body.first() else {
+            panic!("expected a message");
+        };
+        test(message.value.as_ref().unwrap());
+    }
+
+    const SOURCE: &str = "msg = { $count ->
+    [one] { $count } item for { $user }
+   *[other] { $gender ->
+        [female] her { $count }
+       *[other] their { $count }
+    }
+}{ $count }
+";
+
+    fn variables(pattern: &Pattern<&str>) -> Vec<String> {
+        selectors(pattern)
+            .iter()
+            .map(|selector| match selector {
+                Selector::Variable(name) => name.to_string(),
+                Selector::Number(name, _) => format!("NUMBER({})", name),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn finds_selectors_on_variables() {
+        with_pattern(SOURCE, |pattern| {
+            assert_eq!(variables(pattern), ["count", "gender"]);
+        });
+        with_pattern(
+            "msg = { $count } and { NUMBER($n, type: \"ordinal\") ->\n *[other] x\n}{ LEN($s) ->\n *[other] y\n}\n",
+            |pattern| {
+                assert_eq!(variables(pattern), ["NUMBER(n)"]);
+            },
+        );
+    }
+
+    #[test]
+    fn applies_number_options() {
+        with_pattern(
+            "msg = { NUMBER($n, type: \"ordinal\", minimumFractionDigits: 2) ->\n *[other] x\n}\n",
+            |pattern| {
+                let mut args = FluentArgs::new();
+                args.set("n", 3);
+                let Some(FluentValue::Number(number)) = selectors(pattern)[0].value(&args) else {
+                    panic!("expected a number");
+                };
+                assert_eq!(number.options.minimum_fraction_digits, Some(2));
+                assert!(selectors(pattern)[0].value(&FluentArgs::new()).is_none());
+            },
+        );
+    }
+
+    #[test]
+    fn replaces_only_selectors() {
+        with_pattern(SOURCE, |pattern| {
+            let renamed = replace_selectors(pattern, &|selector| {
+                (*selector == Selector::Variable("count")).then_some("n")
+            });
+            assert_eq!(variables(&renamed), ["n", "gender"]);
+
+            let mut displayed = Vec::new();
+            for element in &renamed.elements {
+                if let PatternElement::Placeable {
+                    expression: Expression::Inline(InlineExpression::VariableReference { id }),
+                } = element
+                {
+                    displayed.push(id.name);
+                }
+            }
+            assert_eq!(displayed, ["count"]);
+        });
+        with_pattern(
+            "msg = { NUMBER($n) ->\n *[other] { NUMBER($n) }\n}\n",
+            |pattern| {
+                let replaced = replace_selectors(pattern, &|_| Some("n#selector"));
+                assert_eq!(variables(&replaced), ["n#selector"]);
+            },
+        );
+    }
+}
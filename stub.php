@@ -12,6 +12,10 @@ namespace FluentPhp
         public function getErrors(): array {}
     }
 
+    /**
+     * When a PHP function registered with addFunction() throws, its exception
+     * is available from getPrevious().
+     */
     class ResolverException extends Exception
     {
         /**
@@ -22,6 +26,29 @@ namespace FluentPhp
 
     class CacheException extends Exception {}
 
+    class AttributeNotFoundException extends Exception {}
+
+    /**
+     * Implemented by objects that format themselves and choose their own
+     * variant when passed as message arguments.
+     */
+    interface FluentValue
+    {
+        /**
+         * The text shown for the object in a placeable.
+         *
+         * @param string $locale BCP-47 tag of the bundle locale, e.g. "de-AT"
+         */
+        public function toFluentString(string $locale): string;
+
+        /**
+         * The value select expressions match variant keys against: a string
+         * for identifier keys, a number for plural categories and numeric
+         * keys, or null to use the default variant.
+         */
+        public function toFluentSelector(): string|int|float|null;
+    }
+
     /**
      * A parsed FTL resource that can be added to one or more bundles.
      */
@@ -41,6 +68,25 @@ namespace FluentPhp
          * @throws Exception if the file cannot be read
          */
         public static function fromFile(string $path): self {}
+
+        /**
+         * @return list<string> in source order
+         */
+        public function getMessageIds(): array {}
+
+        /**
+         * @return list<string> in source order, with their leading "-"
+         */
+        public function getTermIds(): array {}
+
+        /**
+         * Describe a message: its attribute names, the variables and the
+         * message and term references in its value and attributes, and the
+         * comment directly above it.
+         *
+         * @return array{attributes: list<string>, variables: list<string>, messages: list<string>, terms: list<string>, comment: ?string}|null
+         */
+        public function getMessageInfo(string $messageId): ?array {}
     }
 
     /**
@@ -135,12 +181,58 @@ namespace FluentPhp
 
     }
 
+    /**
+     * Named functions shared by many bundles. Functions added, replaced or
+     * removed later apply to every bundle using the registry.
+     */
+    final class FunctionRegistry
+    {
+        public function __construct() {}
+
+        /**
+         * Add a PHP function, replacing any function of the same name.
+         *
+         * @param callable(): mixed $callable
+         * @param 'array'|'named' $namedArgs see FluentBundle::addFunction()
+         * @throws Exception if the callable is invalid, the name is one of NUMBER, DATETIME, LIST, PROP and KEY, or the mode is unknown
+         */
+        public function addFunction(string $name, callable $callable, string $namedArgs = 'array'): void {}
+
+        /**
+         * Add groups of native functions, replacing any functions of the same
+         * names. See FluentBundle::addBuiltins().
+         *
+         * @param list<'compare'|'string'> $groups
+         * @throws Exception if a group is unknown
+         */
+        public function addBuiltins(array $groups): void {}
+
+        /**
+         * @return bool whether the function was registered
+         */
+        public function removeFunction(string $name): bool {}
+
+        public function hasFunction(string $name): bool {}
+
+        /**
+         * @return list<string> in alphabetical order
+         */
+        public function getFunctionNames(): array {}
+    }
+
+    /**
+     * Messages for one locale. Numbers (including GMP and BcMath\Number) and
+     * DateTimeInterface values are formatted for the locale, indexed arrays are
+     * joined as lists, and the built-in NUMBER(), DATETIME(), LIST(), PROP()
+     * and KEY() functions are available to every resource.
+     */
     class FluentBundle
     {
         /**
-         * @throws Exception if the language identifier is invalid
+         * @param array{useIsolating?: bool, pseudoLocalization?: 'accented'|'bidi'|null} $options overrides for the ini defaults
+         * @throws Exception if the language identifier is invalid, or an option is unknown or has the wrong type
          */
-        public function __construct(string $langCode) {}
+        public function __construct(string $langCode, array $options = []) {}
 
         /**
          * Add a parsed resource to the bundle.
@@ -156,10 +248,35 @@ namespace FluentPhp
         public function addResource(string|FluentResource $resource): void {}
 
         /**
+         * Fluent named arguments (`FOO($x, style: "short")`) are passed as a
+         * trailing associative array (only when present) with $namedArgs
+         * 'array', or as PHP 8 named parameters with 'named'.
+         *
          * @param callable(): mixed $callable
-         * @throws Exception if a function with that name is already registered
+         * @param 'array'|'named' $namedArgs
+         * @throws Exception if a function with that name is already registered (including the built-ins NUMBER, DATETIME, LIST, PROP and KEY), or the mode is unknown
+         */
+        public function addFunction(string $name, callable $callable, string $namedArgs = 'array'): void {}
+
+        /**
+         * Add opt-in groups of native functions: 'compare' (EQ, GT, LT, GTE,
+         * LTE, returning "true" or "false") and 'string' (STR, UPPER, LOWER,
+         * CAPITALIZE, TRIM, LENGTH).
+         *
+         * @param list<'compare'|'string'> $groups
+         * @throws Exception if a group is unknown or one of its functions has the name of a registered function
+         */
+        public function addBuiltins(array $groups): void {}
+
+        /**
+         * Use the functions of a registry, including functions added to it
+         * later. The bundle's own functions take precedence over registry
+         * functions of the same name, and a function cannot be added to the
+         * bundle under a name the registry already provides.
+         *
+         * @throws Exception if the bundle already uses a different registry
          */
-        public function addFunction(string $name, callable $callable): void {}
+        public function setFunctionRegistry(FunctionRegistry $registry): void {}
 
         /**
          * @param array<string, mixed> $parameters
@@ -168,6 +285,325 @@ namespace FluentPhp
          */
         public function formatPattern(string $messageId, array $parameters): string {}
 
+        /**
+         * Format a message without throwing on resolver errors. The value is
+         * Fluent's best-effort output, with placeholders such as `{$name}`
+         * for anything that could not be resolved.
+         *
+         * @param array<string, mixed> $parameters
+         * @return array{value: string, errors: list<string>}
+         * @throws Exception if the message is not found or has no value, or an argument type is unsupported
+         */
+        public function formatPatternWithErrors(string $messageId, array $parameters = []): array {}
+
+        /**
+         * Format one attribute of a message, e.g. `login-input.placeholder`.
+         *
+         * @param array<string, mixed> $parameters
+         * @throws Exception if the message is not found, or an argument type is unsupported
+         * @throws AttributeNotFoundException if the message has no attribute with that name
+         * @throws ResolverException if the pattern references undefined variables or functions
+         */
+        public function formatAttribute(string $messageId, string $attributeName, array $parameters = []): string {}
+
+        /**
+         * Format a message's value and every attribute in one call.
+         *
+         * Resolver errors from all patterns are reported together in a single
+         * ResolverException.
+         *
+         * @param array<string, mixed> $parameters
+         * @return array{value: ?string, attributes: array<string, string>}
+         * @throws Exception if the message is not found, or an argument type is unsupported
+         * @throws ResolverException if any pattern references undefined variables or functions
+         */
+        public function formatMessage(string $messageId, array $parameters = []): array {}
+
+        public function hasMessage(string $messageId): bool {}
+
+        public function hasAttribute(string $messageId, string $attributeName): bool {}
+
+        /**
+         * @return list<string> in the order their resources were added
+         */
+        public function getMessageIds(): array {}
+
+        /**
+         * Describe a message like FluentResource::getMessageInfo(), from the
+         * definition the bundle uses.
+         *
+         * @return array{attributes: list<string>, variables: list<string>, messages: list<string>, terms: list<string>, comment: ?string}|null
+         */
+        public function getMessageInfo(string $messageId): ?array {}
+
+        /**
+         * @return list<Locale>
+         */
+        public function getLocales(): array {}
+
+        /**
+         * The writing direction of the locale, from its script.
+         *
+         * @return 'ltr'|'rtl'
+         */
+        public function getDirection(): string {}
+
+        /**
+         * Wrap placeables in Unicode isolation marks (FSI/PDI). Defaults to the
+         * fluent.use_isolating ini setting, which is off unless set.
+         */
+        public function setUseIsolating(bool $useIsolating): void {}
+
+        /**
+         * Pseudo-translate the literal text of messages, leaving placeables
+         * intact, or stop with null. Defaults to the fluent.pseudo_localization
+         * ini setting, which is off unless set.
+         *
+         * @param 'accented'|'bidi'|null $strategy
+         * @throws Exception if the strategy is unknown
+         */
+        public function setPseudoLocalization(?string $strategy): void {}
+
+        /**
+         * Pass each piece of literal message text, but not placeables, through
+         * a callable, or remove it with null. Runs before pseudo-localization.
+         *
+         * @param (callable(string): string)|null $transform
+         * @param bool $pure cache results by text
+         * @throws Exception if $transform is not callable
+         */
+        public function setTransform(?callable $transform, bool $pure = false): void {}
+
+        /**
+         * Format the values placeables show, except strings, or remove the
+         * formatter with null. Returning null keeps the default formatting.
+         *
+         * @param (callable(mixed, 'number'|'datetime'|'list'|'bool'|'object', string): ?string)|null $formatter
+         * @throws Exception if $formatter is not callable
+         */
+        public function setFormatter(?callable $formatter): void {}
+
+        /**
+         * The CLDR plural category $n selects, using the same rules as select
+         * expressions. Numeric strings keep their fraction digits ('1.0' is
+         * "other" in English).
+         *
+         * @param 'cardinal'|'ordinal' $type
+         * @return 'zero'|'one'|'two'|'few'|'many'|'other'
+         * @throws Exception if $n is not a number or numeric string, or the type is unknown
+         */
+        public function getPluralCategory(int|float|string $n, string $type = 'cardinal'): string {}
+
+        /**
+         * The plural categories the locale uses, in CLDR order.
+         *
+         * @param 'cardinal'|'ordinal' $type
+         * @return list<'zero'|'one'|'two'|'few'|'many'|'other'>
+         * @throws Exception if the type is unknown
+         */
+        public function getPluralCategories(string $type = 'cardinal'): array {}
+
+        /**
+         * Choose what formatting does when a message, value or attribute is
+         * missing, or a pattern fails to resolve: throw, emit an E_WARNING and
+         * return the partial output, or silently return the message id.
+         * Defaults to the fluent.error_policy ini setting.
+         *
+         * @param 'throw'|'warn'|'fallback' $policy
+         * @throws Exception if the policy is unknown
+         */
+        public function setErrorPolicy(string $policy): void {}
+
+        /**
+         * @return 'throw'|'warn'|'fallback'
+         */
+        public function getErrorPolicy(): string {}
+
+        /**
+         * Choose how an exception thrown by a PHP function surfaces when
+         * formatting throws: as the previous exception of a ResolverException
+         * ('wrap', the default), or rethrown as-is ('rethrow').
+         *
+         * @param 'wrap'|'rethrow' $mode
+         * @throws Exception if the mode is unknown
+         */
+        public function setFunctionExceptionMode(string $mode): void {}
+
+        /**
+         * @return 'wrap'|'rethrow'
+         */
+        public function getFunctionExceptionMode(): string {}
+    }
+
+    /**
+     * Formats messages from an ordered chain of locales, falling back to the
+     * next locale when a message is missing (e.g. de-AT -> de -> en).
+     *
+     * Resources are loaded through ResourceCache::fromFile(). Each resource
+     * path may contain a `{locale}` placeholder, replaced with every locale in
+     * turn; files that do not exist for a locale are skipped, but a path that
+     * exists for no locale throws.
+     */
+    final class Localization
+    {
+        /**
+         * @param list<string> $locales locales in fallback order
+         * @param list<string> $resourcePaths FTL file paths, optionally containing `{locale}`
+         * @param FunctionRegistry|null $functions functions for every locale's bundle
+         * @throws Exception if no locale is given, a language identifier is invalid, a file cannot be read, or resources contain duplicate entries
+         * @throws ParserException if an FTL file contains syntax errors
+         * @throws CacheException if the cache is unavailable
+         */
+        public function __construct(array $locales, array $resourcePaths, ?FunctionRegistry $functions = null) {}
+
+        /**
+         * Register a PHP callable as a Fluent function in every locale.
+         *
+         * @param callable(): mixed $callable
+         * @param 'array'|'named' $namedArgs see FluentBundle::addFunction()
+         * @throws Exception if a function with that name is already registered, or the mode is unknown
+         */
+        public function addFunction(string $name, callable $callable, string $namedArgs = 'array'): void {}
+
+        /**
+         * Add groups of native functions to every locale. See
+         * FluentBundle::addBuiltins().
+         *
+         * @param list<'compare'|'string'> $groups
+         * @throws Exception if a group is unknown or one of its functions has the name of a registered function
+         */
+        public function addBuiltins(array $groups): void {}
+
+        /**
+         * Use the functions of a registry in every locale, as passing it to
+         * the constructor does.
+         *
+         * @throws Exception if the bundles already use a different registry
+         */
+        public function setFunctionRegistry(FunctionRegistry $registry): void {}
+
+        /**
+         * Format a message from the first locale that has it.
+         *
+         * @param array<string, mixed> $parameters
+         * @throws Exception if no locale has the message, it has no value, or an argument type is unsupported
+         * @throws ResolverException if the pattern references undefined variables or functions
+         */
+        public function formatPattern(string $messageId, array $parameters = []): string {}
+
         public function hasMessage(string $messageId): bool {}
+
+        /**
+         * Return the locale that serves the message, or null if no locale has it.
+         */
+        public function getMessageLocale(string $messageId): ?string {}
+
+        /**
+         * @return list<string> the locales in fallback order
+         */
+        public function getLocales(): array {}
+
+        /**
+         * Set the error policy of every locale's bundle, also used when no
+         * locale has the message.
+         *
+         * @param 'throw'|'warn'|'fallback' $policy
+         * @throws Exception if the policy is unknown
+         */
+        public function setErrorPolicy(string $policy): void {}
+
+        /**
+         * @return 'throw'|'warn'|'fallback'
+         */
+        public function getErrorPolicy(): string {}
+
+        /**
+         * Set the function exception mode of every locale's bundle.
+         *
+         * @param 'wrap'|'rethrow' $mode
+         * @throws Exception if the mode is unknown
+         */
+        public function setFunctionExceptionMode(string $mode): void {}
+
+        /**
+         * @return 'wrap'|'rethrow'
+         */
+        public function getFunctionExceptionMode(): string {}
+    }
+
+    /**
+     * A parsed BCP-47 language identifier, plus locale negotiation helpers.
+     */
+    final class Locale
+    {
+        /**
+         * @throws Exception if the tag is not a valid language identifier; the
+         *                   message names the first invalid subtag
+         */
+        public function __construct(string $tag) {}
+
+        public function getLanguage(): string {}
+
+        public function getScript(): ?string {}
+
+        public function getRegion(): ?string {}
+
+        /**
+         * @return list<string>
+         */
+        public function getVariants(): array {}
+
+        /**
+         * The canonical form of the tag, e.g. "en-US" for "en_us".
+         */
+        public function __toString(): string {}
+
+        /**
+         * Compare two locales. A side treated as a range matches any locale
+         * that fills in the subtags it leaves out ("en" as a range matches
+         * "en-US").
+         *
+         * @throws Exception if $other is not a valid language identifier
+         */
+        public function matches(Locale|string $other, bool $selfAsRange = false, bool $otherAsRange = false): bool {}
+
+        /**
+         * Add likely subtags, e.g. "zh-TW" becomes "zh-Hant-TW".
+         */
+        public function maximize(): Locale {}
+
+        /**
+         * Remove subtags implied by likely subtags, e.g. "en-Latn-US" becomes "en".
+         */
+        public function minimize(): Locale {}
+
+        /**
+         * Pick the best available locales for a list of requested ones.
+         *
+         * Strategies:
+         * - filtering: every available locale that matches a requested one, best first
+         * - matching: the best available match for each requested locale
+         * - lookup: the single best match
+         *
+         * The default locale is appended when it is not already in the result
+         * (filtering, matching), or returned when nothing matched (lookup).
+         * Available locales are returned exactly as given. Requested locales
+         * that are not valid language identifiers are ignored.
+         *
+         * @param list<string> $requested
+         * @param list<string> $available
+         * @param 'filtering'|'matching'|'lookup' $strategy
+         * @return list<string>
+         * @throws Exception if an available or default locale is invalid, or the strategy is unknown
+         */
+        public static function negotiate(array $requested, array $available, string $default, string $strategy = 'filtering'): array {}
+
+        /**
+         * Parse an HTTP Accept-Language header into language tags ordered by
+         * q-value. Rejected (q=0), wildcard and invalid entries are dropped.
+         *
+         * @return list<string>
+         */
+        public static function parseAcceptLanguage(string $header): array {}
     }
 }
@@ -0,0 +1,51 @@
+--TEST--
+Advanced example with the compare builtins and PROP
+--FILE--
+<?php
+$resource = <<<'FTL'
+    # Select expression only allows string/numeric types as selector.
+    # The LT builtin returns "true" or "false", so it can be used directly.
+    log-time = User { $user->name} logged in at { $date->format("Y-m-d G:i:a") } {LT($date, $deadline) ->
+            [true] before
+            *[false] after
+        } deadline.
+    FTL;
+
+/**
+  * Pre-process the FTL to replace PHP style property access with the
+  * PROP builtin and method calls with a Fluent function.
+  */
+function preProcess(string $resource): string
+{
+    return preg_replace(
+        [
+            '~(\$[A-Za-z][A-Za-z0-9_]*)->([A-Za-z][A-Za-z0-9_]*)\(~',
+            '~(\$[A-Za-z][A-Za-z0-9_]*)->([A-Za-z][A-Za-z0-9_]*)~',
+        ],
+        ['OBJ_METHOD(\1, "\2", ', 'PROP(\1, "\2")'],
+        $resource
+    );
+}
+
+try {
+    $bundle = new FluentPhp\FluentBundle('en');
+    $bundle->addBuiltins(['compare']);
+    $bundle->addFunction('OBJ_METHOD', fn ($obj, $method, ...$args) => $obj->{$method}(...$args));
+
+    $bundle->addResource(preProcess($resource));
+
+    $response = $bundle->formatPattern('log-time', [
+        'date' => new \DateTimeImmutable('2023-03-22T15:22:00'),
+        'deadline' => new \DateTimeImmutable('2023-03-23'),
+        'user' => (object)['name' => 'John Doe'],
+    ]);
+    // Output: User John Doe logged in at 2023-03-22 15:22:pm before deadline.
+    echo $response, PHP_EOL;
+} catch (\Exception $e) {
+    print_r($e);
+}
+?>
+===DONE===
+--EXPECT--
+User John Doe logged in at 2023-03-22 15:22:pm before deadline.
+===DONE===
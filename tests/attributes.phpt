@@ -0,0 +1,80 @@
+--TEST--
+Test FluentBundle::formatAttribute and FluentBundle::hasAttribute
+--FILE--
+<?php
+
+$resource = <<<'FTL'
+    login-input = Predefined value
+        .placeholder = email@example.com
+        .aria-label = Login input value
+        .title = Type your login email, { $name }
+    attr-only =
+        .label = Attribute only
+    FTL;
+
+$bundle = new FluentPhp\FluentBundle('en');
+$bundle->addResource($resource);
+
+echo "--- 1: format attributes ---\n";
+echo $bundle->formatAttribute('login-input', 'placeholder'), PHP_EOL;
+echo $bundle->formatAttribute('login-input', 'aria-label', []), PHP_EOL;
+echo $bundle->formatAttribute('login-input', 'title', ['name' => 'John']), PHP_EOL;
+echo $bundle->formatAttribute('attr-only', 'label'), PHP_EOL;
+
+echo "--- 2: hasAttribute ---\n";
+var_dump($bundle->hasAttribute('login-input', 'placeholder'));
+var_dump($bundle->hasAttribute('login-input', 'missing'));
+var_dump($bundle->hasAttribute('missing', 'placeholder'));
+
+echo "--- 3: missing attribute ---\n";
+try {
+    $bundle->formatAttribute('login-input', 'missing');
+} catch (FluentPhp\AttributeNotFoundException $e) {
+    echo get_class($e), "\n";
+    echo $e->getMessage(), "\n";
+    var_dump($e instanceof FluentPhp\Exception);
+}
+
+echo "--- 4: missing message ---\n";
+try {
+    $bundle->formatAttribute('missing', 'placeholder');
+} catch (FluentPhp\Exception $e) {
+    echo get_class($e), "\n";
+    echo $e->getMessage(), "\n";
+}
+
+echo "--- 5: resolver error ---\n";
+try {
+    $bundle->formatAttribute('login-input', 'title');
+} catch (FluentPhp\ResolverException $e) {
+    echo get_class($e), "\n";
+    echo $e->getMessage(), "\n";
+    var_dump($e->getErrors());
+}
+?>
+===DONE===
+--EXPECT--
+--- 1: format attributes ---
+email@example.com
+Login input value
+Type your login email, John
+Attribute only
+--- 2: hasAttribute ---
+bool(true)
+bool(false)
+bool(false)
+--- 3: missing attribute ---
+FluentPhp\AttributeNotFoundException
+Attribute "missing" not found on message "login-input".
+bool(true)
+--- 4: missing message ---
+FluentPhp\Exception
+Message "missing" not found.
+--- 5: resolver error ---
+FluentPhp\ResolverException
+Resolution failed for message "login-input.title" with error: Unknown variable: $name
+array(1) {
+  [0]=>
+  string(23) "Unknown variable: $name"
+}
+===DONE===
@@ -0,0 +1,67 @@
+--TEST--
+Bidi isolation of placeables and the text direction of a bundle
+--FILE--
+<?php
+
+$resource = <<<'FTL'
+    greeting = مرحبا { $name }!
+    name = { $name }
+    FTL;
+
+function show(string $text): string
+{
+    return strtr($text, ["\u{2068}" => '<FSI>', "\u{2069}" => '<PDI>']);
+}
+
+echo "--- 1: isolation is off by default ---\n";
+$bundle = new FluentPhp\FluentBundle('ar');
+$bundle->addResource($resource);
+echo show($bundle->formatPattern('greeting', ['name' => 'Anna'])), "\n";
+
+echo "--- 2: setUseIsolating() ---\n";
+$bundle->setUseIsolating(true);
+echo show($bundle->formatPattern('greeting', ['name' => 'Anna'])), "\n";
+echo show($bundle->formatPattern('name', ['name' => 'Anna'])), "\n";
+$bundle->setUseIsolating(false);
+echo show($bundle->formatPattern('greeting', ['name' => 'Anna'])), "\n";
+
+echo "--- 3: constructor option ---\n";
+$bundle = new FluentPhp\FluentBundle('ar', ['useIsolating' => true]);
+$bundle->addResource($resource);
+echo show($bundle->formatPattern('greeting', ['name' => 'Anna'])), "\n";
+foreach ([['useIsolating' => 'yes'], ['isolate' => true]] as $options) {
+    try {
+        new FluentPhp\FluentBundle('ar', $options);
+    } catch (FluentPhp\Exception $e) {
+        echo $e->getMessage(), "\n";
+    }
+}
+
+echo "--- 4: getDirection() ---\n";
+foreach (['en', 'ar', 'he-IL', 'fa', 'ur', 'az-Arab', 'az', 'ku-Latn', 'mn-Mong'] as $locale) {
+    echo $locale, ': ', (new FluentPhp\FluentBundle($locale))->getDirection(), "\n";
+}
+?>
+===DONE===
+--EXPECT--
+--- 1: isolation is off by default ---
+مرحبا Anna!
+--- 2: setUseIsolating() ---
+مرحبا <FSI>Anna<PDI>!
+Anna
+مرحبا Anna!
+--- 3: constructor option ---
+مرحبا <FSI>Anna<PDI>!
+The "useIsolating" option must be a boolean.
+Unknown bundle option "isolate"; expected "useIsolating" or "pseudoLocalization".
+--- 4: getDirection() ---
+en: ltr
+ar: rtl
+he-IL: rtl
+fa: rtl
+ur: rtl
+az-Arab: rtl
+az: ltr
+ku-Latn: ltr
+mn-Mong: ltr
+===DONE===
@@ -0,0 +1,128 @@
+--TEST--
+Opt-in compare and string builtins via addBuiltins()
+--FILE--
+<?php
+
+$resource = <<<'FTL'
+    stock = { GT($count, 10) ->
+        [true] In stock
+       *[false] Only { $count } left
+    }
+    deadline = { LT($date, $deadline) ->
+        [true] on time
+       *[false] late
+    }
+    checks = { EQ($a, $b) } { GT($a, $b) } { LT($a, $b) } { GTE($a, $b) } { LTE($a, $b) }
+    city = { UPPER($city) } { LOWER($city) } { CAPITALIZE($city) }
+    name = [{ TRIM($name) }] has { LENGTH(TRIM($name)) } characters
+    str = { STR($value) } { STR(EQ($value, 1)) ->
+        [true] one
+       *[false] not one
+    }
+    letters = { LENGTH($name) ->
+        [one] one letter
+true not one
+1234.5 not one
+       *[other] { LENGTH($name) } letters
+    }
+    FTL;
+
+function bundle(string $locale): FluentPhp\FluentBundle
+{
+    $bundle = new FluentPhp\FluentBundle($locale);
+    $bundle->addResource($GLOBALS['resource']);
+    $bundle->addBuiltins(['compare', 'string']);
+    return $bundle;
+}
+
+$en = bundle('en');
+
+echo "--- 1: comparisons select variants ---\n";
+echo $en->formatPattern('stock', ['count' => 12]), "\n";
+echo $en->formatPattern('stock', ['count' => 3]), "\n";
+echo $en->formatPattern('deadline', [
+    'date' => new DateTimeImmutable('2023-03-22T12:00:00'),
+    'deadline' => new DateTimeImmutable('2023-03-23'),
+]), "\n";
+echo $en->formatPattern('deadline', [
+    'date' => new DateTimeImmutable('2023-03-24'),
+    'deadline' => new DateTimeImmutable('2023-03-23'),
+]), "\n";
+
+echo "--- 2: numbers, numeric strings and strings ---\n";
+echo $en->formatPattern('checks', ['a' => 2, 'b' => 10]), "\n";
+echo $en->formatPattern('checks', ['a' => '2', 'b' => '10']), "\n";
+echo $en->formatPattern('checks', ['a' => 1.5, 'b' => '1.50']), "\n";
+echo $en->formatPattern('checks', ['a' => 'apple', 'b' => 'banana']), "\n";
+
+echo "--- 3: locale-aware case mapping ---\n";
+echo $en->formatPattern('city', ['city' => 'istanbul']), "\n";
+echo bundle('tr')->formatPattern('city', ['city' => 'istanbul']), "\n";
+echo $en->formatPattern('name', ['name' => "  Zoë \n"]), "\n";
+echo $en->formatPattern('letters', ['name' => 'A']), "\n";
+echo $en->formatPattern('str', ['value' => true]), "\n";
+echo $en->formatPattern('str', ['value' => 1234.5]), "\n";
+
+echo "--- 4: unknown groups ---\n";
+try {
+    (new FluentPhp\FluentBundle('en'))->addBuiltins(['compare', 'math']);
+} catch (FluentPhp\Exception $e) {
+    echo $e->getMessage(), "\n";
+}
+
+echo "--- 5: builtin names are taken once added ---\n";
+try {
+    $en->addFunction('EQ', fn($a, $b) => $a == $b);
+} catch (FluentPhp\Exception $e) {
+    echo $e->getMessage(), "\n";
+}
+$bundle = new FluentPhp\FluentBundle('en');
+$bundle->addFunction('UPPER', 'strtoupper');
+try {
+    $bundle->addBuiltins(['string']);
+} catch (FluentPhp\Exception $e) {
+    echo $e->getMessage(), "\n";
+}
+
+echo "--- 6: Localization adds builtins to every locale ---\n";
+$dir = sys_get_temp_dir() . '/fluent-builtins-' . getmypid();
+mkdir("$dir/de", 0777, true);
+mkdir("$dir/en", 0777, true);
+file_put_contents("$dir/de/main.ftl", "shout = { UPPER(\"straße\") }\n");
+file_put_contents("$dir/en/main.ftl", "whisper = { LOWER(\"QUIET\") }\n");
+$l10n = new FluentPhp\Localization(['de', 'en'], ["$dir/{locale}/main.ftl"]);
+$l10n->addBuiltins(['string']);
+echo $l10n->formatPattern('shout'), "\n";
+echo $l10n->formatPattern('whisper'), "\n";
+unlink("$dir/de/main.ftl");
+unlink("$dir/en/main.ftl");
+rmdir("$dir/de");
+rmdir("$dir/en");
+rmdir($dir);
+?>
+===DONE===
+--EXPECT--
+--- 1: comparisons select variants ---
+In stock
+Only 3 left
+on time
+late
+--- 2: numbers, numeric strings and strings ---
+false false true false true
+false false true false true
+true false false true true
+false false true false true
+--- 3: locale-aware case mapping ---
+ISTANBUL istanbul Istanbul
+İSTANBUL istanbul İstanbul
+[Zoë] has 3 characters
+one letter
+--- 4: unknown groups ---
+Unknown builtin group "math"; expected "compare" or "string".
+--- 5: builtin names are taken once added ---
+Attempt to override an existing function: "EQ".
+Attempt to override an existing function: "UPPER".
+--- 6: Localization adds builtins to every locale ---
+STRASSE
+quiet
+===DONE===
@@ -0,0 +1,66 @@
+--TEST--
+DateTimeInterface arguments and the DATETIME builtin
+--FILE--
+<?php
+
+$resource = <<<'FTL'
+    plain = { $d }
+    long = { DATETIME($d, dateStyle: "long", timeStyle: "short") }
+    full-date = { DATETIME($d, dateStyle: "full") }
+    both = { DATETIME($d, dateStyle: "medium", timeStyle: "medium") }
+    utc = { DATETIME($d, timeStyle: "long", timeZone: "UTC") }
+    new-york = { DATETIME($d, timeStyle: "short", timeZone: "America/New_York") }
+    php-format = { YMD($d) }
+    not-a-date = { DATETIME($n) }
+    FTL;
+
+$date = new DateTimeImmutable('2023-03-22T15:22:05+01:00');
+
+function show(string $locale, string $id, array $args): void
+{
+    $bundle = new FluentPhp\FluentBundle($locale);
+    $bundle->addResource($GLOBALS['resource']);
+    $bundle->addFunction('YMD', fn(DateTimeInterface $d) => get_class($d) . ' ' . $d->format('Y-m-d'));
+    $value = $bundle->formatPattern($id, $args);
+    // Make narrow no-break spaces visible.
+    echo "$locale $id: ", str_replace("\u{202f}", '<nnbsp>', $value), "\n";
+}
+
+echo "--- 1: dates format for the locale ---\n";
+show('en', 'plain', ['d' => $date]);
+show('de', 'plain', ['d' => $date]);
+show('en', 'plain', ['d' => new DateTime('2023-03-22T15:22:05+01:00')]);
+
+echo "--- 2: styles ---\n";
+show('en', 'long', ['d' => $date]);
+show('fr', 'full-date', ['d' => $date]);
+show('ja', 'both', ['d' => $date]);
+
+echo "--- 3: time zones ---\n";
+show('en', 'utc', ['d' => $date]);
+show('en', 'new-york', ['d' => $date]);
+
+echo "--- 4: PHP functions receive the original object ---\n";
+show('en', 'php-format', ['d' => $date]);
+
+echo "--- 5: DATETIME needs a date ---\n";
+show('en', 'not-a-date', ['n' => 5]);
+?>
+===DONE===
+--EXPECT--
+--- 1: dates format for the locale ---
+en plain: Mar 22, 2023
+de plain: 22.03.2023
+en plain: Mar 22, 2023
+--- 2: styles ---
+en long: March 22, 2023, 3:22<nnbsp>PM
+fr full-date: mercredi 22 mars 2023
+ja both: 2023/03/22 15:22:05
+--- 3: time zones ---
+en utc: 2:22:05<nnbsp>PM GMT
+en new-york: 10:22<nnbsp>AM
+--- 4: PHP functions receive the original object ---
+en php-format: DateTimeImmutable 2023-03-22
+--- 5: DATETIME needs a date ---
+en not-a-date: DATETIME()
+===DONE===
@@ -0,0 +1,90 @@
+--TEST--
+Enum cases as message arguments and selectors
+--SKIPIF--
+<?php
+if (PHP_VERSION_ID < 80100) {
+    die('skip enums require PHP 8.1');
+}
+?>
+--FILE--
+<?php
+
+enum Status: string
+{
+    case Active = 'active';
+    case Suspended = 'suspended';
+}
+
+enum Priority: int
+{
+    case Low = 1;
+    case High = 3;
+}
+
+enum Suit
+{
+    case Hearts;
+    case Spades;
+}
+
+$bundle = new FluentPhp\FluentBundle('en');
+$bundle->addResource(<<<'FTL'
+    status = { $status ->
+        [active] Your account is active.
+        [suspended] Your account is suspended.
+       *[other] Unknown status.
+    }
+    priority = { $priority ->
+        [1] Low priority
+        [one] Single priority
+       *[other] Priority { $priority }
+    }
+    suit = { $suit ->
+        [Hearts] ♥
+       *[other] ♠
+    }
+    plain = { $value }
+    type = { GET_TYPE($value) }
+    FTL);
+$bundle->addFunction('GET_TYPE', fn($value) => get_debug_type($value));
+$bundle->addFunction('STATUS', fn() => Status::Suspended);
+$bundle->addResource("returned = { STATUS() ->\n    [suspended] suspended\n   *[other] other\n}\n");
+
+echo "--- 1: string-backed enums select by value ---\n";
+echo $bundle->formatPattern('status', ['status' => Status::Active]), "\n";
+echo $bundle->formatPattern('status', ['status' => Status::Suspended]), "\n";
+
+echo "--- 2: int-backed enums are numbers ---\n";
+echo $bundle->formatPattern('priority', ['priority' => Priority::Low]), "\n";
+echo $bundle->formatPattern('priority', ['priority' => Priority::High]), "\n";
+
+echo "--- 3: pure enums select by case name ---\n";
+echo $bundle->formatPattern('suit', ['suit' => Suit::Hearts]), "\n";
+echo $bundle->formatPattern('suit', ['suit' => Suit::Spades]), "\n";
+
+echo "--- 4: enums display and reach functions as their value ---\n";
+echo $bundle->formatPattern('plain', ['value' => Status::Active]), "\n";
+echo $bundle->formatPattern('plain', ['value' => Suit::Spades]), "\n";
+echo $bundle->formatPattern('type', ['value' => Priority::High]), "\n";
+
+echo "--- 5: enums returned by functions ---\n";
+echo $bundle->formatPattern('returned', []), "\n";
+?>
+===DONE===
+--EXPECT--
+--- 1: string-backed enums select by value ---
+Your account is active.
+Your account is suspended.
+--- 2: int-backed enums are numbers ---
+Low priority
+Priority 3
+--- 3: pure enums select by case name ---
+♥
+♠
+--- 4: enums display and reach functions as their value ---
+active
+Spades
+int
+--- 5: enums returned by functions ---
+suspended
+===DONE===
@@ -0,0 +1,25 @@
+--TEST--
+INI fluent.error_policy sets the default error policy
+--INI--
+fluent.error_policy=fallback
+--FILE--
+<?php
+$bundle = new FluentPhp\FluentBundle('en');
+$bundle->addResource('greeting = Hello, { $name }!' . "\n");
+var_dump($bundle->getErrorPolicy());
+var_dump($bundle->formatPattern('greeting', []));
+var_dump($bundle->formatPattern('missing', []));
+
+ini_set('fluent.error_policy', 'throw');
+$strict = new FluentPhp\FluentBundle('en');
+var_dump($strict->getErrorPolicy());
+var_dump($bundle->getErrorPolicy());
+?>
+===DONE===
+--EXPECT--
+string(8) "fallback"
+string(8) "greeting"
+string(7) "missing"
+string(5) "throw"
+string(8) "fallback"
+===DONE===
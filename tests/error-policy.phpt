@@ -0,0 +1,129 @@
+--TEST--
+FluentBundle and Localization: throw, warn and fallback error policies
+--FILE--
+<?php
+set_error_handler(function (int $errno, string $errstr): bool {
+    // Strip the "Class::method(): " prefix PHP adds to extension warnings.
+    echo $errno === E_WARNING ? 'Warning: ' : 'Error: ', preg_replace('/^.*?\(\): /', '', $errstr), "\n";
+    return true;
+});
+
+$resource = <<<'FTL'
+    greeting = Hello, { $name }!
+    attr-only =
+        .label = Label
+    login = Login
+        .title = Log in as { $user }
+    FTL;
+
+$bundle = new FluentPhp\FluentBundle('en');
+$bundle->addResource($resource);
+
+echo "--- 1: default is throw ---\n";
+var_dump($bundle->getErrorPolicy());
+try {
+    $bundle->formatPattern('greeting', []);
+} catch (FluentPhp\ResolverException $e) {
+    echo get_class($e), "\n";
+}
+
+echo "--- 2: warn ---\n";
+$bundle->setErrorPolicy('warn');
+var_dump($bundle->formatPattern('greeting', []));
+var_dump($bundle->formatPattern('missing', []));
+var_dump($bundle->formatPattern('attr-only', []));
+var_dump($bundle->formatAttribute('login', 'title'));
+var_dump($bundle->formatAttribute('login', 'missing'));
+var_dump($bundle->formatMessage('login'));
+
+echo "--- 3: fallback ---\n";
+$bundle->setErrorPolicy('Fallback');
+var_dump($bundle->getErrorPolicy());
+var_dump($bundle->formatPattern('greeting', []));
+var_dump($bundle->formatPattern('missing', []));
+var_dump($bundle->formatAttribute('missing', 'title'));
+var_dump($bundle->formatMessage('login'));
+var_dump($bundle->formatMessage('missing'));
+var_dump($bundle->formatPatternWithErrors('missing'));
+
+echo "--- 4: valid arguments are unaffected ---\n";
+var_dump($bundle->formatPattern('greeting', ['name' => 'Anna']));
+
+echo "--- 5: Localization ---\n";
+$l10n = new FluentPhp\Localization(['en'], []);
+$l10n->setErrorPolicy('warn');
+var_dump($l10n->getErrorPolicy());
+var_dump($l10n->formatPattern('missing'));
+
+echo "--- 6: unknown policy ---\n";
+try {
+    $bundle->setErrorPolicy('ignore');
+} catch (FluentPhp\Exception $e) {
+    echo $e->getMessage(), "\n";
+}
+?>
+===DONE===
+--EXPECT--
+--- 1: default is throw ---
+string(5) "throw"
+FluentPhp\ResolverException
+--- 2: warn ---
+Warning: Resolution failed for message "greeting" with error: Unknown variable: $name
+string(15) "Hello, {$name}!"
+Warning: Message "missing" not found.
+string(7) "missing"
+Warning: Message "attr-only" has no value.
+string(9) "attr-only"
+Warning: Resolution failed for message "login.title" with error: Unknown variable: $user
+string(17) "Log in as {$user}"
+Warning: Attribute "missing" not found on message "login".
+string(13) "login.missing"
+Warning: Resolution failed for message "login" with error: Unknown variable: $user
+array(2) {
+  ["value"]=>
+  string(5) "Login"
+  ["attributes"]=>
+  array(1) {
+    ["title"]=>
+    string(17) "Log in as {$user}"
+  }
+}
+--- 3: fallback ---
+string(8) "fallback"
+string(8) "greeting"
+string(7) "missing"
+string(13) "missing.title"
+array(2) {
+  ["value"]=>
+  string(5) "login"
+  ["attributes"]=>
+  array(1) {
+    ["title"]=>
+    string(11) "login.title"
+  }
+}
+array(2) {
+  ["value"]=>
+  string(7) "missing"
+  ["attributes"]=>
+  array(0) {
+  }
+}
+array(2) {
+  ["value"]=>
+  string(7) "missing"
+  ["errors"]=>
+  array(1) {
+    [0]=>
+    string(28) "Message "missing" not found."
+  }
+}
+--- 4: valid arguments are unaffected ---
+string(12) "Hello, Anna!"
+--- 5: Localization ---
+string(4) "warn"
+Warning: Message "missing" not found in locales: en.
+string(7) "missing"
+--- 6: unknown policy ---
+Unknown error policy "ignore"; expected "throw", "warn" or "fallback".
+===DONE===
@@ -43,11 +43,11 @@ try {
     echo $e->getMessage(), "\n";
 }
 
-echo "--- 5: unsupported argument type array ---\n";
+echo "--- 5: unsupported argument type associative array ---\n";
 try {
     $bundle = new FluentPhp\FluentBundle('en');
     $bundle->addResource('content = { $value }' . "\n");
-    $bundle->formatPattern('content', ['value' => [1, 2, 3]]);
+    $bundle->formatPattern('content', ['value' => ['a' => 1, 'b' => 2]]);
 } catch (FluentPhp\Exception $e) {
     echo get_class($e), "\n";
     echo $e->getMessage(), "\n";
@@ -67,7 +67,7 @@ try {
 --EXPECT--
 --- 1: invalid language identifier ---
 FluentPhp\Exception
-Invalid language identifier.
+Invalid language identifier "not-a-valid-lang!!!": subtag "a" at position 2 is not a valid script, region, or variant.
 --- 2: not a ParserException ---
 correctly not a ParserException
 --- 3: missing message ID ---
@@ -77,7 +77,7 @@ Message "missing" not found.
 bool(true)
 FluentPhp\Exception
 Message "user" has no value.
---- 5: unsupported argument type array ---
+--- 5: unsupported argument type associative array ---
 FluentPhp\Exception
 Unsupported type for argument "value": Array.
 --- 6: unsupported argument type resource ---
@@ -0,0 +1,87 @@
+--TEST--
+FluentValue objects format themselves and drive selectors
+--FILE--
+<?php
+
+final class Money implements FluentPhp\FluentValue
+{
+    public function __construct(private int $cents, private string $currency) {}
+
+    public function toFluentString(string $locale): string
+    {
+        $amount = number_format($this->cents / 100, 2, $locale === 'de' ? ',' : '.', '');
+        return $locale === 'de' ? "$amount {$this->currency}" : "{$this->currency} $amount";
+    }
+
+    public function toFluentSelector(): int
+    {
+        return intdiv($this->cents, 100);
+    }
+}
+
+final class Person implements FluentPhp\FluentValue
+{
+    public function __construct(private string $name, private ?string $gender) {}
+
+    public function toFluentString(string $locale): string
+    {
+        return $this->name;
+    }
+
+    public function toFluentSelector(): ?string
+    {
+        return $this->gender;
+    }
+}
+
+$resource = <<<'FTL'
+    price = Price: { $price }
+    dollars = { $price ->
+        [one] One dollar ({ $price })
+       *[other] Many dollars ({ $price })
+    }
+    shared = { $user ->
+        [female] { $user } shared her photo.
+        [male] { $user } shared his photo.
+       *[other] { $user } shared their photo.
+    }
+    type = { GET_TYPE($user) }
+    FTL;
+
+$en = new FluentPhp\FluentBundle('en');
+$en->addResource($resource);
+$en->addFunction('GET_TYPE', fn($value) => get_class($value));
+$de = new FluentPhp\FluentBundle('de');
+$de->addResource($resource);
+
+echo "--- 1: toFluentString() receives the bundle locale ---\n";
+echo $en->formatPattern('price', ['price' => new Money(1250, 'EUR')]), "\n";
+echo $de->formatPattern('price', ['price' => new Money(1250, 'EUR')]), "\n";
+
+echo "--- 2: toFluentSelector() picks the variant ---\n";
+echo $en->formatPattern('dollars', ['price' => new Money(150, 'USD')]), "\n";
+echo $en->formatPattern('dollars', ['price' => new Money(1250, 'USD')]), "\n";
+echo $en->formatPattern('shared', ['user' => new Person('Anna', 'female')]), "\n";
+echo $en->formatPattern('shared', ['user' => new Person('Ben', 'male')]), "\n";
+
+echo "--- 3: a null selector uses the default variant ---\n";
+echo $en->formatPattern('shared', ['user' => new Person('Sam', null)]), "\n";
+
+echo "--- 4: functions still receive the object ---\n";
+echo $en->formatPattern('type', ['user' => new Person('Anna', 'female')]), "\n";
+?>
+===DONE===
+--EXPECT--
+--- 1: toFluentString() receives the bundle locale ---
+Price: EUR 12.50
+Price: 12,50 EUR
+--- 2: toFluentSelector() picks the variant ---
+One dollar (USD 1.50)
+Many dollars (USD 12.50)
+Anna shared her photo.
+Ben shared his photo.
+--- 3: a null selector uses the default variant ---
+Sam shared their photo.
+--- 4: functions still receive the object ---
+Person
+===DONE===
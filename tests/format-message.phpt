@@ -0,0 +1,88 @@
+--TEST--
+Test FluentBundle::formatMessage
+--FILE--
+<?php
+
+$resource = <<<'FTL'
+    login-input = Predefined value
+        .placeholder = email@example.com
+        .aria-label = Login input value
+    attr-only =
+        .label = Hello, { $name }
+    value-only = Just a value
+    broken = { $a }
+        .title = { $b }
+    FTL;
+
+$bundle = new FluentPhp\FluentBundle('en');
+$bundle->addResource($resource);
+
+echo "--- 1: value and attributes ---\n";
+var_dump($bundle->formatMessage('login-input'));
+
+echo "--- 2: attribute-only message ---\n";
+var_dump($bundle->formatMessage('attr-only', ['name' => 'John']));
+
+echo "--- 3: value-only message ---\n";
+var_dump($bundle->formatMessage('value-only', []));
+
+echo "--- 4: errors aggregated across patterns ---\n";
+try {
+    $bundle->formatMessage('broken');
+} catch (FluentPhp\ResolverException $e) {
+    echo $e->getMessage(), "\n";
+    var_dump($e->getErrors());
+}
+
+echo "--- 5: missing message ---\n";
+try {
+    $bundle->formatMessage('missing');
+} catch (FluentPhp\Exception $e) {
+    echo get_class($e), "\n";
+    echo $e->getMessage(), "\n";
+}
+?>
+===DONE===
+--EXPECT--
+--- 1: value and attributes ---
+array(2) {
+  ["value"]=>
+  string(16) "Predefined value"
+  ["attributes"]=>
+  array(2) {
+    ["placeholder"]=>
+    string(17) "email@example.com"
+    ["aria-label"]=>
+    string(17) "Login input value"
+  }
+}
+--- 2: attribute-only message ---
+array(2) {
+  ["value"]=>
+  NULL
+  ["attributes"]=>
+  array(1) {
+    ["label"]=>
+    string(11) "Hello, John"
+  }
+}
+--- 3: value-only message ---
+array(2) {
+  ["value"]=>
+  string(12) "Just a value"
+  ["attributes"]=>
+  array(0) {
+  }
+}
+--- 4: errors aggregated across patterns ---
+Resolution failed for message "broken" with 2 errors: Unknown variable: $a; Unknown variable: $b
+array(2) {
+  [0]=>
+  string(20) "Unknown variable: $a"
+  [1]=>
+  string(20) "Unknown variable: $b"
+}
+--- 5: missing message ---
+FluentPhp\Exception
+Message "missing" not found.
+===DONE===
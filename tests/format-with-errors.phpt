@@ -0,0 +1,66 @@
+--TEST--
+Test FluentBundle::formatPatternWithErrors
+--FILE--
+<?php
+
+$resource = <<<'FTL'
+    greeting = Hello, { $name }!
+    broken = { $first } and { UNKNOWN() }
+    attr-only =
+        .label = Label
+    FTL;
+
+$bundle = new FluentPhp\FluentBundle('en');
+$bundle->addResource($resource);
+
+echo "--- 1: no errors ---\n";
+var_dump($bundle->formatPatternWithErrors('greeting', ['name' => 'Anna']));
+
+echo "--- 2: partial output with errors ---\n";
+var_dump($bundle->formatPatternWithErrors('greeting'));
+var_dump($bundle->formatPatternWithErrors('broken', []));
+
+echo "--- 3: missing message or value still throws ---\n";
+foreach (['missing', 'attr-only'] as $id) {
+    try {
+        $bundle->formatPatternWithErrors($id);
+    } catch (FluentPhp\Exception $e) {
+        echo get_class($e), ": ", $e->getMessage(), "\n";
+    }
+}
+?>
+===DONE===
+--EXPECT--
+--- 1: no errors ---
+array(2) {
+  ["value"]=>
+  string(12) "Hello, Anna!"
+  ["errors"]=>
+  array(0) {
+  }
+}
+--- 2: partial output with errors ---
+array(2) {
+  ["value"]=>
+  string(15) "Hello, {$name}!"
+  ["errors"]=>
+  array(1) {
+    [0]=>
+    string(23) "Unknown variable: $name"
+  }
+}
+array(2) {
+  ["value"]=>
+  string(24) "{$first} and {UNKNOWN()}"
+  ["errors"]=>
+  array(2) {
+    [0]=>
+    string(24) "Unknown variable: $first"
+    [1]=>
+    string(27) "Unknown function: UNKNOWN()"
+  }
+}
+--- 3: missing message or value still throws ---
+FluentPhp\Exception: Message "missing" not found.
+FluentPhp\Exception: Message "attr-only" has no value.
+===DONE===
@@ -0,0 +1,45 @@
+--TEST--
+A bundle without a formatter formatted from another bundle's formatter does not use it
+--FILE--
+<?php
+
+final class Money
+{
+    public function __construct(public int $cents, public string $currency) {}
+}
+
+$amounts = new FluentPhp\FluentBundle('en-GB');
+$amounts->addResource(<<<'FTL'
+    amount = { NUMBER($amount, minimumFractionDigits: 2) } { $currency }
+    FTL);
+
+$seen = [];
+$bundle = new FluentPhp\FluentBundle('en-GB');
+$bundle->addResource(<<<'FTL'
+    price = Total: { $price }
+    FTL);
+$bundle->setFormatter(function (mixed $value, string $type, string $locale) use ($amounts, &$seen): ?string {
+    $seen[] = $type;
+    if ($value instanceof Money) {
+        return $amounts->formatPattern('amount', [
+            'amount' => $value->cents / 100,
+            'currency' => $value->currency,
+        ]);
+    }
+    throw new RuntimeException("unexpected $type");
+});
+
+var_dump($bundle->formatPatternWithErrors('price', ['price' => new Money(1250, 'EUR')]));
+echo implode(', ', $seen), "\n";
+?>
+===DONE===
+--EXPECT--
+array(2) {
+  ["value"]=>
+  string(16) "Total: 12.50 EUR"
+  ["errors"]=>
+  array(0) {
+  }
+}
+object
+===DONE===
@@ -0,0 +1,94 @@
+--TEST--
+setFormatter() formats the values placeables show
+--FILE--
+<?php
+
+final class Money
+{
+    public function __construct(public int $cents, public string $currency) {}
+}
+
+$resource = <<<'FTL'
+    price = Total: { $price }
+    paid = Paid on { $date } by { $name }
+    items = { $count ->
+        [one] one item
+       *[other] { $count } items
+    }
+    flag = Enabled: { $flag }
+    tags = Tags: { $tags }
+    FTL;
+
+$seen = [];
+$formatter = function (mixed $value, string $type, string $locale) use (&$seen): ?string {
+    $seen[] = "$type@$locale";
+    if ($value instanceof Money) {
+        return sprintf('%s %.2f', $value->currency, $value->cents / 100);
+    }
+    if ($value instanceof DateTimeInterface) {
+        return $value->format('d/m/Y');
+    }
+    if ($type === 'list') {
+        return implode(' | ', $value);
+    }
+    return null;
+};
+
+$bundle = new FluentPhp\FluentBundle('en-GB');
+$bundle->addResource($resource);
+$bundle->setFormatter($formatter);
+
+echo "--- 1: replacement strings ---\n";
+echo $bundle->formatPattern('price', ['price' => new Money(1250, 'EUR')]), "\n";
+echo $bundle->formatPattern('paid', [
+    'date' => new DateTimeImmutable('2023-03-22'),
+    'name' => 'Anna',
+]), "\n";
+echo $bundle->formatPattern('tags', ['tags' => ['red', 'blue']]), "\n";
+
+echo "--- 2: null falls back to the default ---\n";
+echo $bundle->formatPattern('items', ['count' => 1]), "\n";
+echo $bundle->formatPattern('items', ['count' => 1234]), "\n";
+echo $bundle->formatPattern('flag', ['flag' => true]), "\n";
+echo implode(', ', $seen), "\n";
+
+echo "--- 3: exceptions ---\n";
+$bundle->setFormatter(function (mixed $value, string $type, string $locale): ?string {
+    throw new RuntimeException('boom');
+});
+var_dump($bundle->formatPatternWithErrors('items', ['count' => 5]));
+
+echo "--- 4: removing the formatter ---\n";
+$bundle->setFormatter(null);
+echo $bundle->formatPattern('items', ['count' => 1234]), "\n";
+try {
+    $bundle->setFormatter('no_such_function');
+} catch (FluentPhp\Exception $e) {
+    echo $e->getMessage(), "\n";
+}
+?>
+===DONE===
+--EXPECT--
+--- 1: replacement strings ---
+Total: EUR 12.50
+Paid on 22/03/2023 by Anna
+Tags: red | blue
+--- 2: null falls back to the default ---
+one item
+1,234 items
+Enabled: true
+object@en-GB, datetime@en-GB, list@en-GB, number@en-GB, bool@en-GB
+--- 3: exceptions ---
+array(2) {
+  ["value"]=>
+  string(7) "5 items"
+  ["errors"]=>
+  array(1) {
+    [0]=>
+    string(40) "formatter() threw RuntimeException: boom"
+  }
+}
+--- 4: removing the formatter ---
+1,234 items
+setFormatter() expects a valid callable.
+===DONE===
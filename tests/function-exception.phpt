@@ -0,0 +1,104 @@
+--TEST--
+Exceptions thrown by PHP functions are surfaced by FluentBundle
+--FILE--
+<?php
+set_error_handler(function (int $errno, string $errstr): bool {
+    echo 'Warning: ', preg_replace('/^.*?\(\): /', '', $errstr), "\n";
+    return true;
+});
+
+$resource = <<<'FTL'
+    greeting = Hello { BOOM() }!
+    mixed = { $missing } { BOOM() }
+    FTL;
+
+$bundle = new FluentPhp\FluentBundle('en');
+$bundle->addResource($resource);
+$line = __LINE__ + 2;
+$bundle->addFunction('BOOM', function () {
+    throw new RuntimeException('boom', 42);
+});
+
+echo "--- 1: wrapped as previous of ResolverException ---\n";
+var_dump($bundle->getFunctionExceptionMode());
+try {
+    $bundle->formatPattern('greeting', []);
+} catch (FluentPhp\ResolverException $e) {
+    echo $e->getMessage(), "\n";
+    var_dump($e->getErrors());
+    $previous = $e->getPrevious();
+    echo get_class($previous), ': ', $previous->getMessage(), ' (', $previous->getCode(), ")\n";
+    var_dump($previous->getLine() === $line);
+}
+
+echo "--- 2: combined with other resolver errors ---\n";
+try {
+    $bundle->formatPattern('mixed', []);
+} catch (FluentPhp\ResolverException $e) {
+    echo $e->getMessage(), "\n";
+    echo get_class($e->getPrevious()), "\n";
+}
+
+echo "--- 3: rethrown directly ---\n";
+$bundle->setFunctionExceptionMode('rethrow');
+try {
+    $bundle->formatPattern('greeting', []);
+} catch (RuntimeException $e) {
+    echo get_class($e), ': ', $e->getMessage(), "\n";
+    var_dump($e->getPrevious());
+}
+
+echo "--- 4: error policy still applies ---\n";
+$bundle->setErrorPolicy('warn');
+var_dump($bundle->formatPattern('greeting', []));
+var_dump($bundle->formatPatternWithErrors('greeting'));
+$bundle->setErrorPolicy('fallback');
+var_dump($bundle->formatPattern('greeting', []));
+
+echo "--- 5: Localization ---\n";
+$l10n = new FluentPhp\Localization(['en'], []);
+$l10n->setFunctionExceptionMode('rethrow');
+var_dump($l10n->getFunctionExceptionMode());
+
+echo "--- 6: unknown mode ---\n";
+try {
+    $bundle->setFunctionExceptionMode('ignore');
+} catch (FluentPhp\Exception $e) {
+    echo $e->getMessage(), "\n";
+}
+?>
+===DONE===
+--EXPECT--
+--- 1: wrapped as previous of ResolverException ---
+string(4) "wrap"
+Resolution failed for message "greeting" with error: BOOM() threw RuntimeException: boom
+array(1) {
+  [0]=>
+  string(35) "BOOM() threw RuntimeException: boom"
+}
+RuntimeException: boom (42)
+bool(true)
+--- 2: combined with other resolver errors ---
+Resolution failed for message "mixed" with 2 errors: Unknown variable: $missing; BOOM() threw RuntimeException: boom
+RuntimeException
+--- 3: rethrown directly ---
+RuntimeException: boom
+NULL
+--- 4: error policy still applies ---
+Warning: Resolution failed for message "greeting" with error: BOOM() threw RuntimeException: boom
+string(13) "Hello BOOM()!"
+array(2) {
+  ["value"]=>
+  string(13) "Hello BOOM()!"
+  ["errors"]=>
+  array(1) {
+    [0]=>
+    string(35) "BOOM() threw RuntimeException: boom"
+  }
+}
+string(8) "greeting"
+--- 5: Localization ---
+string(7) "rethrow"
+--- 6: unknown mode ---
+Unknown function exception mode "ignore"; expected "wrap" or "rethrow".
+===DONE===
@@ -0,0 +1,75 @@
+--TEST--
+Test FluentBundle::addFunction with named arguments
+--FILE--
+<?php
+
+$resource = <<<'FTL'
+    shout = { SHOUT($name, suffix: "!", times: 3) }
+    shout-plain = { SHOUT($name) }
+    count-args = { COUNT_ARGS($name) }
+    pad = { PAD($name, length: 8, pad: "*") }
+    pad-default = { PAD($name) }
+    FTL;
+
+echo "--- 1: trailing associative array (default) ---\n";
+$bundle = new FluentPhp\FluentBundle('en');
+$bundle->addResource($resource);
+$bundle->addFunction('SHOUT', function (string $value, array $options = []) {
+    $options += ['suffix' => '', 'times' => 1];
+    return strtoupper($value) . str_repeat($options['suffix'], (int) $options['times']);
+});
+$bundle->addFunction('COUNT_ARGS', fn(...$args) => count($args));
+echo $bundle->formatPattern('shout', ['name' => 'anna']), PHP_EOL;
+echo $bundle->formatPattern('shout-plain', ['name' => 'anna']), PHP_EOL;
+
+echo "--- 2: no array is appended without named arguments ---\n";
+echo $bundle->formatPattern('count-args', ['name' => 'anna']), PHP_EOL;
+
+echo "--- 3: PHP 8 named parameters ---\n";
+$bundle = new FluentPhp\FluentBundle('en');
+$bundle->addResource($resource);
+$bundle->addFunction('PAD', function (string $value, int $length = 6, string $pad = '.') {
+    return str_pad($value, $length, $pad);
+}, 'named');
+echo $bundle->formatPattern('pad', ['name' => 'anna']), PHP_EOL;
+echo $bundle->formatPattern('pad-default', ['name' => 'anna']), PHP_EOL;
+
+echo "--- 4: Localization forwards the mode ---\n";
+$dir = sys_get_temp_dir() . '/fluent_named_args_' . getmypid();
+mkdir("$dir/en", 0777, true);
+file_put_contents("$dir/en/main.ftl", $resource);
+$l10n = new FluentPhp\Localization(['en'], ["$dir/{locale}/main.ftl"]);
+$l10n->addFunction('PAD', fn(string $value, int $length = 6, string $pad = '.') => str_pad($value, $length, $pad), 'named');
+echo $l10n->formatPattern('pad', ['name' => 'anna']), PHP_EOL;
+unlink("$dir/en/main.ftl");
+rmdir("$dir/en");
+rmdir($dir);
+
+echo "--- 5: invalid arguments ---\n";
+try {
+    $bundle->addFunction('OTHER', fn() => '', 'positional');
+} catch (FluentPhp\Exception $e) {
+    echo $e->getMessage(), "\n";
+}
+try {
+    $bundle->addFunction('OTHER', 'no_such_function');
+} catch (FluentPhp\Exception $e) {
+    echo $e->getMessage(), "\n";
+}
+?>
+===DONE===
+--EXPECT--
+--- 1: trailing associative array (default) ---
+ANNA!!!
+ANNA
+--- 2: no array is appended without named arguments ---
+1
+--- 3: PHP 8 named parameters ---
+anna****
+anna..
+--- 4: Localization forwards the mode ---
+anna****
+--- 5: invalid arguments ---
+Unknown named argument mode "positional"; expected "array" or "named".
+addFunction() expects a valid callable.
+===DONE===
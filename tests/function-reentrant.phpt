@@ -0,0 +1,38 @@
+--TEST--
+Test PHP functions that format messages calling the same function
+--FILE--
+<?php
+
+$resource = <<<'FTL'
+    nested = { NEST($depth) }
+    leaf = leaf
+    FTL;
+
+$nest = function (FluentPhp\FluentBundle $bundle) {
+    return function ($depth) use ($bundle) {
+        if ($depth <= 0) {
+            return $bundle->formatPattern('leaf', []);
+        }
+        return '[' . $bundle->formatPattern('nested', ['depth' => $depth - 1]) . ']';
+    };
+};
+
+echo "--- 1: bundle function ---\n";
+$bundle = new FluentPhp\FluentBundle('en');
+$bundle->addResource($resource);
+$bundle->addFunction('NEST', $nest($bundle));
+echo $bundle->formatPattern('nested', ['depth' => 3]), "\n";
+
+echo "--- 2: registry function ---\n";
+$bundle = new FluentPhp\FluentBundle('en');
+$bundle->addResource($resource);
+$registry = new FluentPhp\FunctionRegistry();
+$registry->addFunction('NEST', $nest($bundle));
+$bundle->setFunctionRegistry($registry);
+echo $bundle->formatPattern('nested', ['depth' => 2]), "\n";
+?>
+--EXPECT--
+--- 1: bundle function ---
+[[[leaf]]]
+--- 2: registry function ---
+[[leaf]]
@@ -0,0 +1,158 @@
+--TEST--
+FunctionRegistry shares functions across bundles
+--FILE--
+<?php
+
+$resource = <<<'FTL'
+    greeting = { GREET($name) }
+    shout = { UPPER($name) }
+    wave = { WAVE($name) }
+    count = { NUMBER(COUNT($items)) ->
+        [one] one item
+       *[other] { COUNT($items) } items
+    }
+    FTL;
+
+function bundle(string $locale, FluentPhp\FunctionRegistry $registry): FluentPhp\FluentBundle
+{
+    $bundle = new FluentPhp\FluentBundle($locale);
+    $bundle->addResource($GLOBALS['resource']);
+    $bundle->setFunctionRegistry($registry);
+    return $bundle;
+}
+
+$registry = new FluentPhp\FunctionRegistry();
+$registry->addFunction('GREET', fn($name) => "Hello, $name");
+$registry->addBuiltins(['string']);
+
+$en = bundle('en', $registry);
+$tr = bundle('tr', $registry);
+
+echo "--- 1: one registry, many bundles ---\n";
+echo $en->formatPattern('greeting', ['name' => 'Anna']), "\n";
+echo $tr->formatPattern('greeting', ['name' => 'Anna']), "\n";
+echo $en->formatPattern('shout', ['name' => 'istanbul']), "\n";
+echo $tr->formatPattern('shout', ['name' => 'istanbul']), "\n";
+
+echo "--- 2: functions added later reach attached bundles ---\n";
+$registry->addFunction('COUNT', fn(array $items) => count($items));
+echo $en->formatPattern('count', ['items' => ['a']]), "\n";
+echo $tr->formatPattern('count', ['items' => ['a', 'b']]), "\n";
+
+echo "--- 3: replacing functions ---\n";
+$registry->addFunction('GREET', fn($name) => "Hi, $name");
+echo $en->formatPattern('greeting', ['name' => 'Anna']), "\n";
+$registry->addFunction('UPPER', fn($name) => "[$name]");
+echo $tr->formatPattern('shout', ['name' => 'istanbul']), "\n";
+$registry->addBuiltins(['string']);
+echo $tr->formatPattern('shout', ['name' => 'istanbul']), "\n";
+
+echo "--- 4: removing functions ---\n";
+var_dump($registry->removeFunction('GREET'));
+var_dump($registry->removeFunction('GREET'));
+var_dump($registry->hasFunction('GREET'));
+var_dump($en->formatPatternWithErrors('greeting', ['name' => 'Anna']));
+print_r($registry->getFunctionNames());
+
+echo "--- 5: bundle functions take precedence ---\n";
+$bundle = new FluentPhp\FluentBundle('en');
+$bundle->addResource($resource);
+$bundle->addFunction('GREET', fn($name) => "Own, $name");
+$registry->addFunction('GREET', fn($name) => "Shared, $name");
+$bundle->setFunctionRegistry($registry);
+echo $bundle->formatPattern('greeting', ['name' => 'Anna']), "\n";
+$bundle->addFunction('WAVE', fn($name) => "Own wave, $name");
+$registry->addFunction('WAVE', fn($name) => "Shared wave, $name");
+echo $bundle->formatPattern('wave', ['name' => 'Anna']), "\n";
+$registry->removeFunction('WAVE');
+try {
+    $bundle->addFunction('COUNT', 'count');
+} catch (FluentPhp\Exception $e) {
+    echo $e->getMessage(), "\n";
+}
+try {
+    $bundle->setFunctionRegistry(new FluentPhp\FunctionRegistry());
+} catch (FluentPhp\Exception $e) {
+    echo $e->getMessage(), "\n";
+}
+
+echo "--- 6: core functions cannot be replaced ---\n";
+try {
+    $registry->addFunction('NUMBER', fn($n) => $n);
+} catch (FluentPhp\Exception $e) {
+    echo $e->getMessage(), "\n";
+}
+try {
+    $registry->addFunction('BAD', 'not a function');
+} catch (FluentPhp\Exception $e) {
+    echo $e->getMessage(), "\n";
+}
+
+echo "--- 7: Localization applies the registry to every locale ---\n";
+$dir = sys_get_temp_dir() . '/fluent-registry-' . getmypid();
+mkdir("$dir/de", 0777, true);
+mkdir("$dir/en", 0777, true);
+file_put_contents("$dir/de/main.ftl", "greeting = { GREET(\"Anna\") }\n");
+file_put_contents("$dir/en/main.ftl", "farewell = { UPPER(\"bye\") }\n");
+$l10n = new FluentPhp\Localization(['de', 'en'], ["$dir/{locale}/main.ftl"], $registry);
+echo $l10n->formatPattern('greeting'), "\n";
+echo $l10n->formatPattern('farewell'), "\n";
+$l10n = new FluentPhp\Localization(['de', 'en'], ["$dir/{locale}/main.ftl"]);
+$l10n->setFunctionRegistry($registry);
+echo $l10n->formatPattern('greeting'), "\n";
+unlink("$dir/de/main.ftl");
+unlink("$dir/en/main.ftl");
+rmdir("$dir/de");
+rmdir("$dir/en");
+rmdir($dir);
+?>
+===DONE===
+--EXPECT--
+--- 1: one registry, many bundles ---
+Hello, Anna
+Hello, Anna
+ISTANBUL
+İSTANBUL
+--- 2: functions added later reach attached bundles ---
+one item
+2 items
+--- 3: replacing functions ---
+Hi, Anna
+[istanbul]
+İSTANBUL
+--- 4: removing functions ---
+bool(true)
+bool(false)
+bool(false)
+array(2) {
+  ["value"]=>
+  string(7) "GREET()"
+  ["errors"]=>
+  array(1) {
+    [0]=>
+    string(25) "Unknown function: GREET()"
+  }
+}
+Array
+(
+    [0] => CAPITALIZE
+    [1] => COUNT
+    [2] => LENGTH
+    [3] => LOWER
+    [4] => STR
+    [5] => TRIM
+    [6] => UPPER
+)
+--- 5: bundle functions take precedence ---
+Own, Anna
+Own wave, Anna
+Attempt to override an existing function: "COUNT".
+The bundle already uses another FunctionRegistry.
+--- 6: core functions cannot be replaced ---
+Attempt to override an existing function: "NUMBER".
+addFunction() expects a valid callable.
+--- 7: Localization applies the registry to every locale ---
+Shared, Anna
+BYE
+Shared, Anna
+===DONE===
@@ -35,8 +35,7 @@ try {
     print_r($e);
 }
 
-// Variables preserve their PHP type when passed to functions, except integers
-// which become floats (int -> FluentValue::Number -> float).
+// Variables preserve their PHP type when passed to functions.
 // Return values from one function are also correctly typed when passed to another.
 echo "--- 3: variable types passed to functions ---\n";
 $resource = <<<'FTL'
@@ -52,14 +51,14 @@ try {
 
     // Variable input types received by function
     echo $bundle->formatPattern('var-type', ['val' => 'hello']), PHP_EOL;       // string
-    echo $bundle->formatPattern('var-type', ['val' => 42]), PHP_EOL;            // int -> double
+    echo $bundle->formatPattern('var-type', ['val' => 42]), PHP_EOL;            // integer
     echo $bundle->formatPattern('var-type', ['val' => 3.14]), PHP_EOL;          // double
     echo $bundle->formatPattern('var-type', ['val' => true]), PHP_EOL;          // boolean
     echo $bundle->formatPattern('var-type', ['val' => new stdClass()]), PHP_EOL;// object
 
     // Return types passed through IDENTITY to GET_TYPE
     echo $bundle->formatPattern('ret-type', ['val' => 'hello']), PHP_EOL;       // string
-    echo $bundle->formatPattern('ret-type', ['val' => 42]), PHP_EOL;            // int -> double -> double
+    echo $bundle->formatPattern('ret-type', ['val' => 42]), PHP_EOL;            // integer
     echo $bundle->formatPattern('ret-type', ['val' => 3.14]), PHP_EOL;          // double
     echo $bundle->formatPattern('ret-type', ['val' => true]), PHP_EOL;          // boolean
     echo $bundle->formatPattern('ret-type', ['val' => new stdClass()]), PHP_EOL;// object
@@ -76,12 +75,12 @@ true
 false
 --- 3: variable types passed to functions ---
 string
-double
+integer
 double
 boolean
 object
 string
-double
+integer
 double
 boolean
 object
@@ -0,0 +1,155 @@
+--TEST--
+Listing and describing messages and terms of resources and bundles
+--FILE--
+<?php
+
+$source = <<<'FTL'
+    ### Resource comment
+
+    -brand = Firefox
+        .gender = masculine
+
+    # Shown on the toolbar.
+    # Keep it short.
+    save = Save { $file } with { -brand }
+        .title = { save-hint.tooltip } { $file }
+        .accesskey = S
+
+    save-hint = { $count ->
+        [one] { NUMBER($count) } file by { $user }
+       *[other] { $count } files
+    }
+        .tooltip = { -brand.gender ->
+            [masculine] His
+           *[other] Their
+        } { -app(tone: "formal") }
+
+    -app = App
+    FTL;
+
+$resource = FluentPhp\FluentResource::fromString($source);
+
+echo "--- 1: resource ids ---\n";
+var_dump($resource->getMessageIds());
+var_dump($resource->getTermIds());
+
+echo "--- 2: message info ---\n";
+var_dump($resource->getMessageInfo('save'));
+var_dump($resource->getMessageInfo('save-hint'));
+var_dump($resource->getMessageInfo('-brand'));
+var_dump($resource->getMessageInfo('missing'));
+
+echo "--- 3: bundles ---\n";
+$bundle = new FluentPhp\FluentBundle('en');
+$bundle->addResource($resource);
+try {
+    $bundle->addResource("save = Overridden\nclose = Close\n");
+} catch (FluentPhp\Exception $e) {
+    echo get_class($e), "\n";
+}
+var_dump($bundle->getMessageIds());
+var_dump($bundle->getMessageInfo('save')['comment']);
+var_dump($bundle->getMessageInfo('close'));
+var_dump($bundle->getMessageInfo('missing'));
+?>
+===DONE===
+--EXPECT--
+--- 1: resource ids ---
+array(2) {
+  [0]=>
+  string(4) "save"
+  [1]=>
+  string(9) "save-hint"
+}
+array(2) {
+  [0]=>
+  string(6) "-brand"
+  [1]=>
+  string(4) "-app"
+}
+--- 2: message info ---
+array(5) {
+  ["attributes"]=>
+  array(2) {
+    [0]=>
+    string(5) "title"
+    [1]=>
+    string(9) "accesskey"
+  }
+  ["variables"]=>
+  array(1) {
+    [0]=>
+    string(4) "file"
+  }
+  ["messages"]=>
+  array(1) {
+    [0]=>
+    string(17) "save-hint.tooltip"
+  }
+  ["terms"]=>
+  array(1) {
+    [0]=>
+    string(6) "-brand"
+  }
+  ["comment"]=>
+  string(36) "Shown on the toolbar.
+Keep it short."
+}
+array(5) {
+  ["attributes"]=>
+  array(1) {
+    [0]=>
+    string(7) "tooltip"
+  }
+  ["variables"]=>
+  array(2) {
+    [0]=>
+    string(5) "count"
+    [1]=>
+    string(4) "user"
+  }
+  ["messages"]=>
+  array(0) {
+  }
+  ["terms"]=>
+  array(2) {
+    [0]=>
+    string(13) "-brand.gender"
+    [1]=>
+    string(4) "-app"
+  }
+  ["comment"]=>
+  NULL
+}
+NULL
+NULL
+--- 3: bundles ---
+FluentPhp\Exception
+array(3) {
+  [0]=>
+  string(4) "save"
+  [1]=>
+  string(9) "save-hint"
+  [2]=>
+  string(5) "close"
+}
+string(36) "Shown on the toolbar.
+Keep it short."
+array(5) {
+  ["attributes"]=>
+  array(0) {
+  }
+  ["variables"]=>
+  array(0) {
+  }
+  ["messages"]=>
+  array(0) {
+  }
+  ["terms"]=>
+  array(0) {
+  }
+  ["comment"]=>
+  NULL
+}
+NULL
+===DONE===
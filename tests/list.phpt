@@ -0,0 +1,95 @@
+--TEST--
+Indexed arrays as list values and the LIST builtin
+--FILE--
+<?php
+
+$resource = <<<'FTL'
+    plain = { $items }
+    either = { LIST($items, type: "disjunction") }
+    short = { LIST($items, style: "short") }
+    unit = { LIST($items, type: "unit", style: "narrow") }
+    invited = { NUMBER($names) ->
+        [0] Nobody is invited.
+        [one] { $names } is invited.
+       *[other] { $names } are invited.
+    }
+    guests = { $names ->
+        [0] No guests.
+        [one] One guest: { $names }.
+       *[other] Guests: { $names }.
+    }
+    echo = { ECHO($items) }
+    FTL;
+
+function show(string $locale, string $id, array $items): void
+{
+    $bundle = new FluentPhp\FluentBundle($locale);
+    $bundle->addResource($GLOBALS['resource']);
+    $bundle->addFunction('ECHO', fn(array $items) => json_encode($items));
+    $args = in_array($id, ['invited', 'guests']) ? ['names' => $items] : ['items' => $items];
+    echo "$locale $id: ", $bundle->formatPattern($id, $args), "\n";
+}
+
+echo "--- 1: lists use the locale's conjunction ---\n";
+show('en', 'plain', ['Anna', 'Ben', 'Carla']);
+show('de', 'plain', ['Anna', 'Ben', 'Carla']);
+show('en', 'plain', ['Anna', 'Ben']);
+show('en', 'plain', [1234.5, 2, 'three']);
+
+echo "--- 2: LIST options ---\n";
+show('en', 'either', ['tea', 'coffee', 'juice']);
+show('fr', 'either', ['thé', 'café', 'jus']);
+show('en', 'short', ['A', 'B', 'C']);
+show('en', 'unit', ['5 ft', '7 in']);
+
+echo "--- 3: lists select plural variants by their count ---\n";
+show('en', 'invited', []);
+show('en', 'invited', ['Anna']);
+show('en', 'invited', ['Anna', 'Ben']);
+show('en', 'guests', []);
+show('en', 'guests', ['Anna']);
+show('en', 'guests', ['Anna', 'Ben']);
+
+echo "--- 4: functions receive arrays ---\n";
+show('en', 'echo', ['a', 1, true]);
+
+echo "--- 5: associative arrays are not lists ---\n";
+try {
+    show('en', 'plain', ['a' => 'Anna']);
+} catch (FluentPhp\Exception $e) {
+    echo $e->getMessage(), "\n";
+}
+
+echo "--- 6: LIST is reserved ---\n";
+try {
+    (new FluentPhp\FluentBundle('en'))->addFunction('LIST', fn($items) => $items);
+} catch (FluentPhp\Exception $e) {
+    echo $e->getMessage(), "\n";
+}
+?>
+===DONE===
+--EXPECT--
+--- 1: lists use the locale's conjunction ---
+en plain: Anna, Ben, and Carla
+de plain: Anna, Ben und Carla
+en plain: Anna and Ben
+en plain: 1,234.5, 2, and three
+--- 2: LIST options ---
+en either: tea, coffee, or juice
+fr either: thé, café ou jus
+en short: A, B, & C
+en unit: 5 ft 7 in
+--- 3: lists select plural variants by their count ---
+en invited: Nobody is invited.
+en invited: Anna is invited.
+en invited: Anna and Ben are invited.
+en guests: No guests.
+en guests: One guest: Anna.
+en guests: Guests: Anna and Ben.
+--- 4: functions receive arrays ---
+en echo: ["a",1,true]
+--- 5: associative arrays are not lists ---
+Unsupported type for argument "items": Array.
+--- 6: LIST is reserved ---
+Attempt to override an existing function: "LIST".
+===DONE===
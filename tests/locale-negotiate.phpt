@@ -0,0 +1,62 @@
+--TEST--
+Locale::negotiate and Locale::parseAcceptLanguage
+--FILE--
+<?php
+use FluentPhp\Locale;
+
+echo "--- 1: parseAcceptLanguage ---\n";
+echo implode(',', Locale::parseAcceptLanguage('fr-CH, fr;q=0.9, en;q=0.8, de;q=0.7, *;q=0.5')), "\n";
+echo implode(',', Locale::parseAcceptLanguage('en;q=0.5, de, pt-br;q=0.5')), "\n";
+echo implode(',', Locale::parseAcceptLanguage('en;q=0, !!, ja')), "\n";
+var_dump(Locale::parseAcceptLanguage(''));
+
+echo "--- 2: strategies ---\n";
+$requested = ['de-AT', 'fr'];
+$available = ['en', 'de', 'de-DE', 'fr-CA', 'fr'];
+echo implode(',', Locale::negotiate($requested, $available, 'en')), "\n";
+echo implode(',', Locale::negotiate($requested, $available, 'en', 'filtering')), "\n";
+echo implode(',', Locale::negotiate($requested, $available, 'en', 'matching')), "\n";
+echo implode(',', Locale::negotiate($requested, $available, 'en', 'lookup')), "\n";
+
+echo "--- 3: default and original spelling ---\n";
+echo implode(',', Locale::negotiate(['ja'], ['en_US', 'pt_BR'], 'en_US')), "\n";
+echo implode(',', Locale::negotiate(['pt-BR'], ['en_US', 'pt_BR'], 'en_US', 'lookup')), "\n";
+
+echo "--- 4: header to negotiation ---\n";
+$requested = Locale::parseAcceptLanguage('de-CH,de;q=0.9,en;q=0.8');
+echo implode(',', Locale::negotiate($requested, ['en', 'de'], 'en', 'matching')), "\n";
+
+echo "--- 5: errors ---\n";
+try {
+    Locale::negotiate(['en'], ['en'], 'en', 'closest');
+} catch (FluentPhp\Exception $e) {
+    echo $e->getMessage(), "\n";
+}
+try {
+    Locale::negotiate(['en'], ['en', '!!'], 'en');
+} catch (FluentPhp\Exception $e) {
+    echo $e->getMessage(), "\n";
+}
+?>
+===DONE===
+--EXPECT--
+--- 1: parseAcceptLanguage ---
+fr-CH,fr,en,de
+de,en,pt-BR
+ja
+array(0) {
+}
+--- 2: strategies ---
+de,de-DE,fr,fr-CA,en
+de,de-DE,fr,fr-CA,en
+de,fr,en
+de
+--- 3: default and original spelling ---
+en_US
+pt_BR
+--- 4: header to negotiation ---
+de,en
+--- 5: errors ---
+Unknown negotiation strategy "closest"; expected "filtering", "matching" or "lookup".
+Invalid language identifier "!!": subtag "!!" at position 1 is not a valid language.
+===DONE===
@@ -0,0 +1,84 @@
+--TEST--
+Locale: subtags, canonical form, matching and likely subtags
+--FILE--
+<?php
+use FluentPhp\Locale;
+
+echo "--- 1: subtags ---\n";
+$locale = new Locale('sr_cyrl_rs_ekavsk');
+var_dump($locale->getLanguage());
+var_dump($locale->getScript());
+var_dump($locale->getRegion());
+var_dump($locale->getVariants());
+echo $locale, "\n";
+
+$locale = new Locale('de');
+var_dump($locale->getScript(), $locale->getRegion(), $locale->getVariants());
+
+echo "--- 2: matches ---\n";
+$en = new Locale('en');
+var_dump($en->matches('en-US'));
+var_dump($en->matches('en-US', true));
+var_dump($en->matches(new Locale('en-GB'), true));
+var_dump((new Locale('en-US'))->matches($en, false, true));
+var_dump($en->matches('fr', true, true));
+
+echo "--- 3: likely subtags ---\n";
+echo (new Locale('zh-TW'))->maximize(), "\n";
+echo (new Locale('en-Latn-US'))->minimize(), "\n";
+echo (new Locale('sr'))->maximize(), "\n";
+
+echo "--- 4: bundle locales ---\n";
+$bundle = new FluentPhp\FluentBundle('pt_br');
+$locales = $bundle->getLocales();
+echo get_class($locales[0]), "\n";
+echo $locales[0], "\n";
+
+echo "--- 5: errors ---\n";
+foreach (['!!', 'en-US-!!', 'zh-Hant-!!'] as $tag) {
+    try {
+        new Locale($tag);
+    } catch (FluentPhp\Exception $e) {
+        echo $e->getMessage(), "\n";
+    }
+}
+try {
+    $en->matches(42);
+} catch (FluentPhp\Exception $e) {
+    echo $e->getMessage(), "\n";
+}
+?>
+===DONE===
+--EXPECT--
+--- 1: subtags ---
+string(2) "sr"
+string(4) "Cyrl"
+string(2) "RS"
+array(1) {
+  [0]=>
+  string(6) "ekavsk"
+}
+sr-Cyrl-RS-ekavsk
+NULL
+NULL
+array(0) {
+}
+--- 2: matches ---
+bool(false)
+bool(true)
+bool(true)
+bool(true)
+bool(false)
+--- 3: likely subtags ---
+zh-Hant-TW
+en
+sr-Cyrl-RS
+--- 4: bundle locales ---
+FluentPhp\Locale
+pt-BR
+--- 5: errors ---
+Invalid language identifier "!!": subtag "!!" at position 1 is not a valid language.
+Invalid language identifier "en-US-!!": subtag "!!" at position 3 is not a valid variant.
+Invalid language identifier "zh-Hant-!!": subtag "!!" at position 3 is not a valid region or variant.
+matches() expects a string or Locale instance.
+===DONE===
@@ -0,0 +1,109 @@
+--TEST--
+Localization formats from the first locale that has the message
+--FILE--
+<?php
+$dir = sys_get_temp_dir() . '/fluent_l10n_' . getmypid();
+foreach (['de-AT', 'de', 'en'] as $locale) {
+    @mkdir("$dir/$locale", 0777, true);
+}
+file_put_contents("$dir/de-AT/main.ftl", "greeting = Servus, { \$name }!\n");
+file_put_contents("$dir/de/main.ftl", "greeting = Hallo, { \$name }!\nfarewell = Tschüss!\n");
+file_put_contents("$dir/en/main.ftl", "greeting = Hello, { \$name }!\nfarewell = Goodbye!\nonly-en = English only\n");
+// de-AT has no extra.ftl; the missing file is skipped.
+file_put_contents("$dir/en/extra.ftl", "extra = Extra\n");
+
+$l10n = new FluentPhp\Localization(
+    ['de-AT', 'de', 'en'],
+    ["$dir/{locale}/main.ftl", "$dir/{locale}/extra.ftl"],
+);
+
+echo "--- 1: fallback chain ---\n";
+echo $l10n->formatPattern('greeting', ['name' => 'Anna']), PHP_EOL;
+echo $l10n->formatPattern('farewell'), PHP_EOL;
+echo $l10n->formatPattern('only-en', []), PHP_EOL;
+echo $l10n->formatPattern('extra'), PHP_EOL;
+
+echo "--- 2: serving locale ---\n";
+var_dump($l10n->getMessageLocale('greeting'));
+var_dump($l10n->getMessageLocale('farewell'));
+var_dump($l10n->getMessageLocale('only-en'));
+var_dump($l10n->getMessageLocale('missing'));
+
+echo "--- 3: hasMessage and getLocales ---\n";
+var_dump($l10n->hasMessage('only-en'));
+var_dump($l10n->hasMessage('missing'));
+var_dump($l10n->getLocales());
+
+echo "--- 4: missing everywhere ---\n";
+try {
+    $l10n->formatPattern('missing');
+} catch (FluentPhp\Exception $e) {
+    echo get_class($e), "\n";
+    echo $e->getMessage(), "\n";
+}
+
+echo "--- 5: functions are added to every locale ---\n";
+file_put_contents("$dir/en/fn.ftl", "shout = { UPPER(\$name) }\n");
+$l10n = new FluentPhp\Localization(['de', 'en'], ["$dir/{locale}/fn.ftl"]);
+$l10n->addFunction('UPPER', fn ($s) => strtoupper($s));
+echo $l10n->formatPattern('shout', ['name' => 'anna']), PHP_EOL;
+
+echo "--- 6: invalid arguments ---\n";
+try {
+    new FluentPhp\Localization([], []);
+} catch (FluentPhp\Exception $e) {
+    echo $e->getMessage(), "\n";
+}
+try {
+    new FluentPhp\Localization(['not-a-valid-lang!!!'], []);
+} catch (FluentPhp\Exception $e) {
+    echo $e->getMessage(), "\n";
+}
+try {
+    // A path missing for some locales is skipped, but not one missing for all.
+    new FluentPhp\Localization(['de', 'en'], ["$dir/{locale}/main.ftl", "$dir/{locale}/mian.ftl"]);
+} catch (FluentPhp\Exception $e) {
+    echo str_replace($dir, '<dir>', $e->getMessage()), "\n";
+}
+
+foreach (glob("$dir/*/*.ftl") as $file) {
+    unlink($file);
+}
+foreach (['de-AT', 'de', 'en'] as $locale) {
+    rmdir("$dir/$locale");
+}
+rmdir($dir);
+?>
+===DONE===
+--EXPECT--
+--- 1: fallback chain ---
+Servus, Anna!
+Tschüss!
+English only
+Extra
+--- 2: serving locale ---
+string(5) "de-AT"
+string(2) "de"
+string(2) "en"
+NULL
+--- 3: hasMessage and getLocales ---
+bool(true)
+bool(false)
+array(3) {
+  [0]=>
+  string(5) "de-AT"
+  [1]=>
+  string(2) "de"
+  [2]=>
+  string(2) "en"
+}
+--- 4: missing everywhere ---
+FluentPhp\Exception
+Message "missing" not found in locales: de-AT, de, en.
+--- 5: functions are added to every locale ---
+ANNA
+--- 6: invalid arguments ---
+Localization requires at least one locale.
+Invalid language identifier "not-a-valid-lang!!!": subtag "a" at position 2 is not a valid script, region, or variant.
+Resource "<dir>/{locale}/mian.ftl" not found in locales: de, en.
+===DONE===
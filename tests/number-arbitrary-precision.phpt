@@ -0,0 +1,63 @@
+--TEST--
+GMP and BcMath\Number arguments are formatted as numbers
+--SKIPIF--
+<?php
+if (!extension_loaded('gmp') || !class_exists('BcMath\Number')) {
+    die('skip requires gmp and PHP 8.4 bcmath');
+}
+?>
+--FILE--
+<?php
+
+$resource = <<<'FTL'
+    plain = { $n }
+    price = { NUMBER($n, style: "currency", currency: "EUR") }
+    items = { $n ->
+        [one] one item
+       *[other] { $n } items
+    }
+    rows = { $n ->
+        [one] one row
+        [few] few rows
+       *[other] other rows
+    }
+    FTL;
+
+function show(string $locale, string $id, object $n): void
+{
+    $bundle = new FluentPhp\FluentBundle($locale);
+    $bundle->addResource($GLOBALS['resource']);
+    $value = $bundle->formatPattern($id, ['n' => $n]);
+    // Make non-breaking spaces visible.
+    echo "$locale $id: ", str_replace("\u{a0}", '<nbsp>', $value), "\n";
+}
+
+echo "--- 1: GMP ---\n";
+show('en', 'plain', gmp_init('123456789012345678901234567890'));
+show('de', 'plain', gmp_init(-1234567));
+show('en', 'items', gmp_init(1));
+show('en', 'items', gmp_init(3));
+show('ru', 'rows', gmp_init('100000000000000000000001'));
+show('ru', 'rows', gmp_init('100000000000000000000002'));
+
+echo "--- 2: BcMath\\Number ---\n";
+show('en', 'plain', new BcMath\Number('19.90'));
+show('de', 'price', new BcMath\Number('1234.50'));
+show('en', 'items', new BcMath\Number('1'));
+show('en', 'items', new BcMath\Number('1.0'));
+?>
+===DONE===
+--EXPECT--
+--- 1: GMP ---
+en plain: 123,456,789,012,345,678,901,234,567,890
+de plain: -1.234.567
+en items: one item
+en items: 3 items
+ru rows: one row
+ru rows: few rows
+--- 2: BcMath\Number ---
+en plain: 19.90
+de price: 1.234,50<nbsp>€
+en items: one item
+en items: 1.0 items
+===DONE===
@@ -0,0 +1,107 @@
+--TEST--
+Large integers and numeric strings keep their digits
+--FILE--
+<?php
+
+$resource = <<<'FTL'
+    plain = { $n }
+    decimal = { NUMBER($n) }
+    price = { NUMBER($n, style: "currency", currency: "USD") }
+    items = { NUMBER($n) ->
+        [one] { NUMBER($n) } item
+       *[other] { NUMBER($n) } items
+    }
+    rows = { $n ->
+        [one] one row
+        [few] few rows
+        [many] many rows
+       *[other] other rows
+    }
+    number-rows = { NUMBER($n) ->
+        [one] one row
+        [few] few rows
+        [many] many rows
+       *[other] other rows
+    }
+    place = { NUMBER($n, type: "ordinal") ->
+        [one] { $n }st
+        [two] { $n }nd
+        [few] { $n }rd
+       *[other] { $n }th
+    }
+    type = { GET_TYPE(NUMBER($n)) }
+    identity = { IDENTITY($n) }
+    FTL;
+
+function show(string $locale, string $id, int|float|string $n): void
+{
+    $bundle = new FluentPhp\FluentBundle($locale);
+    $bundle->addResource($GLOBALS['resource']);
+    $bundle->addFunction('GET_TYPE', fn($val) => gettype($val) . ' ' . var_export($val, true));
+    $bundle->addFunction('IDENTITY', fn($val) => $val);
+    echo "$locale $id: ", $bundle->formatPattern($id, ['n' => $n]), "\n";
+}
+
+echo "--- 1: integers beyond 2^53 ---\n";
+show('en', 'plain', PHP_INT_MAX);
+show('en', 'plain', 9007199254740993);
+show('de', 'plain', -9007199254740993);
+show('en', 'identity', PHP_INT_MAX);
+
+echo "--- 2: numeric strings through NUMBER ---\n";
+show('en', 'plain', '19.90');
+show('en', 'decimal', '19.90');
+show('de', 'decimal', '19.90');
+show('en', 'decimal', '1234567890123456789.25');
+show('en', 'price', '1234.50');
+
+echo "--- 3: fraction digits select plural variants ---\n";
+show('en', 'items', 1);
+show('en', 'items', '1');
+show('en', 'items', '1.0');
+
+echo "--- 4: values passed to functions ---\n";
+show('en', 'type', PHP_INT_MAX);
+show('en', 'type', '42');
+show('en', 'type', 3.0);
+show('en', 'type', '19.90');
+show('en', 'type', '1234567890123456789.25');
+echo "--- 5: values beyond 2^53 select by their digits ---\n";
+show('ru', 'rows', 9007199254741001);
+show('ru', 'rows', 9007199254741002);
+show('ru', 'number-rows', '10000000000000000000001');
+show('ru', 'number-rows', '10000000000000000000001.5');
+show('en', 'place', 9007199254740993);
+show('en', 'place', '10000000000000000000002');
+?>
+===DONE===
+--EXPECT--
+--- 1: integers beyond 2^53 ---
+en plain: 9,223,372,036,854,775,807
+en plain: 9,007,199,254,740,993
+de plain: -9.007.199.254.740.993
+en identity: 9,223,372,036,854,775,807
+--- 2: numeric strings through NUMBER ---
+en plain: 19.90
+en decimal: 19.90
+de decimal: 19,90
+en decimal: 1,234,567,890,123,456,789.25
+en price: $1,234.50
+--- 3: fraction digits select plural variants ---
+en items: 1 item
+en items: 1 item
+en items: 1.0 items
+--- 4: values passed to functions ---
+en type: integer 9223372036854775807
+en type: integer 42
+en type: double 3.0
+en type: double 19.9
+en type: string '1234567890123456789.25'
+--- 5: values beyond 2^53 select by their digits ---
+ru rows: one row
+ru rows: few rows
+ru number-rows: one row
+ru number-rows: other rows
+en place: 9,007,199,254,740,993rd
+en place: 10000000000000000000002nd
+===DONE===
@@ -0,0 +1,94 @@
+--TEST--
+Locale-aware number formatting and the NUMBER builtin
+--FILE--
+<?php
+
+$resource = <<<'FTL'
+    plain = { $n }
+    fixed = { NUMBER($n, minimumFractionDigits: 2) }
+    rounded = { NUMBER($n, maximumFractionDigits: 1) }
+    ungrouped = { NUMBER($n, useGrouping: "false") }
+    padded = { NUMBER($n, minimumIntegerDigits: 3) }
+    significant = { NUMBER($n, maximumSignificantDigits: 2) }
+    percent = { NUMBER($n, style: "percent") }
+    price = { NUMBER($n, style: "currency", currency: "EUR") }
+    price-code = { NUMBER($n, style: "currency", currency: "USD", currencyDisplay: "code") }
+    yen = { NUMBER($n, style: "currency", currency: "JPY") }
+    literal = { 1234.50 }
+    FTL;
+
+function show(string $locale, string $id, int|float $n): void
+{
+    $bundle = new FluentPhp\FluentBundle($locale);
+    $bundle->addResource($GLOBALS['resource']);
+    $value = $bundle->formatPattern($id, ['n' => $n]);
+    // Make non-breaking spaces visible.
+    echo "$locale $id: ", str_replace(["\u{a0}", "\u{202f}"], ['<nbsp>', '<nnbsp>'], $value), "\n";
+}
+
+echo "--- 1: variables use locale separators ---\n";
+show('en', 'plain', 1234567.891);
+show('de', 'plain', 1234567.891);
+show('fr', 'plain', 1234.5);
+show('en-IN', 'plain', 1234567);
+show('en', 'plain', -42);
+show('en', 'plain', 2 / 3);
+
+echo "--- 2: digit options ---\n";
+show('en', 'fixed', 1.5);
+show('en', 'rounded', 1.25);
+show('en', 'ungrouped', 12345);
+show('en', 'padded', 7);
+show('en', 'significant', 123456);
+
+echo "--- 3: percent and currency ---\n";
+show('en', 'percent', 0.256);
+show('de', 'percent', 0.256);
+show('de-CH', 'percent', 0.256);
+show('en', 'price', 1234.5);
+show('de', 'price', 1234.5);
+show('en', 'price', -5);
+show('en', 'price-code', 5);
+show('ja', 'yen', 1234.5);
+
+echo "--- 4: literals keep their precision ---\n";
+show('en', 'literal', 0);
+show('de', 'literal', 0);
+
+echo "--- 5: NUMBER is reserved ---\n";
+try {
+    (new FluentPhp\FluentBundle('en'))->addFunction('NUMBER', fn($n) => $n);
+} catch (FluentPhp\Exception $e) {
+    echo $e->getMessage(), "\n";
+}
+?>
+===DONE===
+--EXPECT--
+--- 1: variables use locale separators ---
+en plain: 1,234,567.891
+de plain: 1.234.567,891
+fr plain: 1<nnbsp>234,5
+en-IN plain: 12,34,567
+en plain: -42
+en plain: 0.667
+--- 2: digit options ---
+en fixed: 1.50
+en rounded: 1.3
+en ungrouped: 12345
+en padded: 007
+en significant: 120,000
+--- 3: percent and currency ---
+en percent: 26%
+de percent: 26<nbsp>%
+de-CH percent: 26%
+en price: €1,234.50
+de price: 1.234,50<nbsp>€
+en price: -€5.00
+en price-code: USD<nbsp>5.00
+ja yen: ￥1,235
+--- 4: literals keep their precision ---
+en literal: 1,234.50
+de literal: 1.234,50
+--- 5: NUMBER is reserved ---
+Attempt to override an existing function: "NUMBER".
+===DONE===
@@ -0,0 +1,85 @@
+--TEST--
+FluentBundle::getPluralCategory() and getPluralCategories()
+--FILE--
+<?php
+
+echo "--- 1: cardinal categories ---\n";
+$en = new FluentPhp\FluentBundle('en');
+foreach ([0, 1, 2, 1.0, 1.5, '1', '1.0', -1] as $n) {
+    echo var_export($n, true), ': ', $en->getPluralCategory($n), "\n";
+}
+
+$ru = new FluentPhp\FluentBundle('ru');
+foreach ([1, 3, 5, 21] as $n) {
+    echo "ru $n: ", $ru->getPluralCategory($n), "\n";
+}
+
+echo "--- 2: ordinal categories ---\n";
+foreach ([1, 2, 3, 4, 11, 22] as $n) {
+    echo "$n: ", $en->getPluralCategory($n, 'ordinal'), "\n";
+}
+
+echo "--- 3: categories used by a locale ---\n";
+echo 'en: ', implode(', ', $en->getPluralCategories()), "\n";
+echo 'en ordinal: ', implode(', ', $en->getPluralCategories('ordinal')), "\n";
+echo 'ru: ', implode(', ', $ru->getPluralCategories('cardinal')), "\n";
+echo 'ar: ', implode(', ', (new FluentPhp\FluentBundle('ar'))->getPluralCategories()), "\n";
+echo 'ja: ', implode(', ', (new FluentPhp\FluentBundle('ja'))->getPluralCategories()), "\n";
+
+echo "--- 4: agrees with select expressions ---\n";
+$en->addResource(<<<'FTL'
+    items = { $n ->
+        [one] one
+       *[other] other
+    }
+    FTL);
+foreach ([1, 2, 1.5] as $n) {
+    echo var_export($n, true), ': ', $en->formatPattern('items', ['n' => $n]), ' ', $en->getPluralCategory($n), "\n";
+}
+
+echo "--- 5: invalid arguments ---\n";
+foreach ([['abc', 'cardinal'], [1, 'plural'], [[1], 'cardinal']] as [$n, $type]) {
+    try {
+        $en->getPluralCategory($n, $type);
+    } catch (FluentPhp\Exception $e) {
+        echo $e->getMessage(), "\n";
+    }
+}
+?>
+===DONE===
+--EXPECT--
+--- 1: cardinal categories ---
+0: other
+1: one
+2: other
+1.0: one
+1.5: other
+'1': one
+'1.0': other
+-1: one
+ru 1: one
+ru 3: few
+ru 5: many
+ru 21: one
+--- 2: ordinal categories ---
+1: one
+2: two
+3: few
+4: other
+11: other
+22: two
+--- 3: categories used by a locale ---
+en: one, other
+en ordinal: one, two, few, other
+ru: one, few, many, other
+ar: zero, one, two, few, many, other
+ja: other
+--- 4: agrees with select expressions ---
+1: one one
+2: other other
+1.5: other other
+--- 5: invalid arguments ---
+Invalid number "abc".
+Unknown plural rule type "plural"; expected "cardinal" or "ordinal".
+getPluralCategory() expects an int, float or numeric string.
+===DONE===
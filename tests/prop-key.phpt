@@ -0,0 +1,113 @@
+--TEST--
+PROP and KEY builtins read properties and keys of arguments
+--FILE--
+<?php
+
+class User
+{
+    public string $name = 'Anna';
+    public ?string $nickname = null;
+    public string $role = 'admin';
+    public array $address = ['city' => 'Berlin'];
+    protected string $email = 'anna@example.com';
+    private string $password = 'secret';
+
+    public function __get(string $name): string
+    {
+        echo "__get($name) called\n";
+        return 'magic';
+    }
+}
+
+$resource = <<<'FTL'
+    name = { PROP($user, "name") } [{ PROP($user, "nickname") }]
+    role = { PROP($user, "role") ->
+        [admin] Administrator
+       *[other] User
+    }
+    city = { KEY(PROP($user, "address"), "city") }
+    hidden = { PROP($user, "email") }|{ PROP($user, "password") }|{ PROP($user, "magic") }
+    key = { KEY($data, "key") }
+    second = { KEY($data, 1) }
+    chained = [{ KEY(KEY($data, "missing"), "deeper") }]
+    plain = { $data }
+    via-message = { key }
+    via-reference = { key } { plain }
+    FTL;
+
+$bundle = new FluentPhp\FluentBundle('en');
+$bundle->addResource($resource);
+$user = new User();
+
+echo "--- 1: public properties ---\n";
+echo $bundle->formatPattern('name', ['user' => $user]), "\n";
+echo $bundle->formatPattern('name', ['user' => (object)['name' => 'Ben', 'nickname' => 'B']]), "\n";
+echo $bundle->formatPattern('role', ['user' => $user]), "\n";
+echo $bundle->formatPattern('city', ['user' => $user]), "\n";
+
+echo "--- 2: arrays, lists and ArrayAccess ---\n";
+echo $bundle->formatPattern('key', ['data' => ['key' => 'value']]), "\n";
+echo $bundle->formatPattern('key', ['data' => new ArrayObject(['key' => 'stored'])]), "\n";
+echo $bundle->formatPattern('second', ['data' => ['a', 'b', 'c']]), "\n";
+echo $bundle->formatPattern('second', ['data' => ['key' => 'value', 1 => 'one']]), "\n";
+
+echo "--- 3: missing values are empty and reported ---\n";
+$result = $bundle->formatPatternWithErrors('hidden', ['user' => $user]);
+echo $result['value'], "\n";
+echo implode("\n", $result['errors']), "\n";
+$result = $bundle->formatPatternWithErrors('chained', ['data' => ['key' => 'value']]);
+echo $result['value'], "\n";
+echo implode("\n", $result['errors']), "\n";
+try {
+    $bundle->formatPattern('key', ['data' => new stdClass()]);
+} catch (FluentPhp\ResolverException $e) {
+    echo $e->getMessage(), "\n";
+}
+
+echo "--- 4: associative arrays are only KEY arguments ---\n";
+echo $bundle->formatPattern('via-message', ['data' => ['key' => 'value']]), "\n";
+foreach (['plain', 'via-reference'] as $id) {
+    try {
+        $bundle->formatPattern($id, ['data' => ['key' => 'value']]);
+    } catch (FluentPhp\Exception $e) {
+        echo $e->getMessage(), "\n";
+    }
+}
+
+echo "--- 5: PROP and KEY are reserved ---\n";
+foreach (['PROP', 'KEY'] as $name) {
+    try {
+        $bundle->addFunction($name, fn($value, $key) => $value);
+    } catch (FluentPhp\Exception $e) {
+        echo $e->getMessage(), "\n";
+    }
+}
+?>
+===DONE===
+--EXPECT--
+--- 1: public properties ---
+Anna []
+Ben [B]
+Administrator
+Berlin
+--- 2: arrays, lists and ArrayAccess ---
+value
+stored
+b
+one
+--- 3: missing values are empty and reported ---
+||
+No value: PROP("email")
+No value: PROP("password")
+No value: PROP("magic")
+[]
+No value: KEY("missing")
+Resolution failed for message "key" with error: No value: KEY("key")
+--- 4: associative arrays are only KEY arguments ---
+value
+Unsupported type for argument "data": Array.
+Unsupported type for argument "data": Array.
+--- 5: PROP and KEY are reserved ---
+Attempt to override an existing function: "PROP".
+Attempt to override an existing function: "KEY".
+===DONE===
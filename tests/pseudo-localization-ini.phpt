@@ -0,0 +1,27 @@
+--TEST--
+INI fluent.pseudo_localization sets the default pseudo-localization
+--INI--
+fluent.pseudo_localization=accented
+--FILE--
+<?php
+$resource = 'greeting = Hello, { $name }!' . "\n";
+
+$bundle = new FluentPhp\FluentBundle('en');
+$bundle->addResource($resource);
+var_dump($bundle->formatPattern('greeting', ['name' => 'Anna']));
+
+$plain = new FluentPhp\FluentBundle('en', ['pseudoLocalization' => null]);
+$plain->addResource($resource);
+var_dump($plain->formatPattern('greeting', ['name' => 'Anna']));
+
+ini_set('fluent.pseudo_localization', '');
+$later = new FluentPhp\FluentBundle('en');
+$later->addResource($resource);
+var_dump($later->formatPattern('greeting', ['name' => 'Anna']));
+?>
+===DONE===
+--EXPECT--
+string(23) "Ħḗḗŀŀǿǿ, Anna!"
+string(12) "Hello, Anna!"
+string(12) "Hello, Anna!"
+===DONE===
@@ -0,0 +1,70 @@
+--TEST--
+Pseudo-localization transforms literal text only
+--FILE--
+<?php
+
+$resource = <<<'FTL'
+    greeting = Hello, { $name }!
+    save = Save
+        .title = Settings
+    items = { $count ->
+        [one] One item
+       *[other] { $count } items
+    }
+    FTL;
+
+function show(string $text): string
+{
+    return strtr($text, ["\u{202E}" => '<RLO>', "\u{202C}" => '<PDF>']);
+}
+
+echo "--- 1: accented ---\n";
+$bundle = new FluentPhp\FluentBundle('en', ['pseudoLocalization' => 'accented']);
+$bundle->addResource($resource);
+echo $bundle->formatPattern('greeting', ['name' => 'Anna']), "\n";
+echo $bundle->formatPattern('save', []), "\n";
+echo $bundle->formatAttribute('save', 'title'), "\n";
+echo $bundle->formatPattern('items', ['count' => 1]), "\n";
+echo $bundle->formatPattern('items', ['count' => 5]), "\n";
+
+echo "--- 2: bidi ---\n";
+$bundle->setPseudoLocalization('bidi');
+echo show($bundle->formatPattern('greeting', ['name' => 'Anna'])), "\n";
+echo show($bundle->formatPattern('items', ['count' => 5])), "\n";
+
+echo "--- 3: turning it off ---\n";
+$bundle->setPseudoLocalization(null);
+echo $bundle->formatPattern('greeting', ['name' => 'Anna']), "\n";
+
+echo "--- 4: unknown strategies ---\n";
+try {
+    $bundle->setPseudoLocalization('upside-down');
+} catch (FluentPhp\Exception $e) {
+    echo $e->getMessage(), "\n";
+}
+foreach ([['pseudoLocalization' => 'x'], ['pseudoLocalization' => true]] as $options) {
+    try {
+        new FluentPhp\FluentBundle('en', $options);
+    } catch (FluentPhp\Exception $e) {
+        echo $e->getMessage(), "\n";
+    }
+}
+?>
+===DONE===
+--EXPECT--
+--- 1: accented ---
+Ħḗḗŀŀǿǿ, Anna!
+Şȧȧṽḗḗ
+Şḗḗŧŧīƞɠş
+Ǿǿƞḗḗ īŧḗḗḿ
+5 īŧḗḗḿş
+--- 2: bidi ---
+<RLO>Hǝʅʅo, <PDF>Anna<RLO>!<PDF>
+5<RLO> ıʇǝɯs<PDF>
+--- 3: turning it off ---
+Hello, Anna!
+--- 4: unknown strategies ---
+Unknown pseudo-localization strategy "upside-down"; expected "accented" or "bidi".
+Unknown pseudo-localization strategy "x"; expected "accented" or "bidi".
+The "pseudoLocalization" option must be a string or null.
+===DONE===
@@ -0,0 +1,68 @@
+--TEST--
+Ordinal plural selection with NUMBER(type: "ordinal")
+--FILE--
+<?php
+
+echo "--- 1: English ordinals ---\n";
+$bundle = new FluentPhp\FluentBundle('en');
+$bundle->addResource(<<<'FTL'
+    place = { NUMBER($pos, type: "ordinal") ->
+        [one] {$pos}st
+        [two] {$pos}nd
+        [few] {$pos}rd
+       *[other] {$pos}th
+    }
+    FTL);
+$places = [];
+foreach ([1, 2, 3, 4, 11, 12, 13, 21, 22, 23, 101, 111, 1002] as $pos) {
+    $places[] = $bundle->formatPattern('place', ['pos' => $pos]);
+}
+echo implode(' ', $places), "\n";
+
+echo "--- 2: Swedish ordinals ---\n";
+$bundle = new FluentPhp\FluentBundle('sv');
+$bundle->addResource(<<<'FTL'
+    place = { NUMBER($pos, type: "ordinal") ->
+        [one] {$pos}:a
+       *[other] {$pos}:e
+    }
+    FTL);
+$places = [];
+foreach ([1, 2, 3, 11, 12, 21, 22, 23] as $pos) {
+    $places[] = $bundle->formatPattern('place', ['pos' => $pos]);
+}
+echo implode(' ', $places), "\n";
+
+echo "--- 3: cardinal remains the default ---\n";
+$bundle = new FluentPhp\FluentBundle('en');
+$bundle->addResource(<<<'FTL'
+    implicit = { $n ->
+        [one] one
+       *[other] other
+    }
+    explicit = { NUMBER($n, type: "cardinal") ->
+        [one] one
+       *[other] other
+    }
+    ordinal = { NUMBER($n, type: "ordinal") ->
+        [one] one
+       *[other] other
+    }
+    FTL);
+foreach ([1, 21] as $n) {
+    echo "$n: ",
+        $bundle->formatPattern('implicit', ['n' => $n]), ' ',
+        $bundle->formatPattern('explicit', ['n' => $n]), ' ',
+        $bundle->formatPattern('ordinal', ['n' => $n]), "\n";
+}
+?>
+===DONE===
+--EXPECT--
+--- 1: English ordinals ---
+1st 2nd 3rd 4th 11th 12th 13th 21st 22nd 23rd 101st 111th 1,002nd
+--- 2: Swedish ordinals ---
+1:a 2:a 3:e 11:e 12:e 21:a 22:a 23:e
+--- 3: cardinal remains the default ---
+1: one one one
+21: other other one
+===DONE===
@@ -0,0 +1,95 @@
+--TEST--
+setTransform() rewrites literal text with a PHP callable
+--FILE--
+<?php
+
+$resource = <<<'FTL'
+    greeting = Bonjour { $name } !
+    apostrophe = C'est { $name } !
+    plain = Bonjour
+    FTL;
+
+function show(string $text): string
+{
+    return strtr($text, ["\u{202F}" => '<NNBSP>']);
+}
+
+$calls = 0;
+$typography = function (string $text) use (&$calls): string {
+    $calls++;
+    return strtr($text, [' !' => "\u{202F}!", "'" => '’']);
+};
+
+$bundle = new FluentPhp\FluentBundle('fr');
+$bundle->addResource($resource);
+
+echo "--- 1: literal text only ---\n";
+$bundle->setTransform($typography);
+echo show($bundle->formatPattern('greeting', ['name' => 'Anna'])), "\n";
+echo show($bundle->formatPattern('apostrophe', ['name' => "O'Brien !"])), "\n";
+
+echo "--- 2: pure callables are cached by text ---\n";
+$calls = 0;
+$bundle->formatPattern('greeting', ['name' => 'Anna']);
+$bundle->formatPattern('greeting', ['name' => 'Ben']);
+var_dump($calls);
+$bundle->setTransform($typography, true);
+$calls = 0;
+$bundle->formatPattern('greeting', ['name' => 'Anna']);
+echo show($bundle->formatPattern('greeting', ['name' => 'Ben'])), "\n";
+var_dump($calls);
+
+echo "--- 3: combined with pseudo-localization ---\n";
+$bundle->setTransform('strtoupper');
+$bundle->setPseudoLocalization('accented');
+echo $bundle->formatPattern('greeting', ['name' => 'Anna']), "\n";
+$bundle->setPseudoLocalization(null);
+
+echo "--- 4: results that are not strings keep the text ---\n";
+$bundle->setTransform(fn(string $text) => null);
+echo $bundle->formatPattern('greeting', ['name' => 'Anna']), "\n";
+
+echo "--- 5: exceptions ---\n";
+$bundle->setTransform(function (string $text): string {
+    throw new RuntimeException('boom');
+});
+try {
+    $bundle->formatPattern('plain', []);
+} catch (FluentPhp\ResolverException $e) {
+    echo $e->getMessage(), "\n";
+    echo get_class($e->getPrevious()), ': ', $e->getPrevious()->getMessage(), "\n";
+}
+$bundle->setErrorPolicy('fallback');
+var_dump($bundle->formatPattern('plain', []));
+$bundle->setErrorPolicy('throw');
+
+echo "--- 6: removing the transform ---\n";
+$bundle->setTransform(null);
+echo $bundle->formatPattern('greeting', ['name' => 'Anna']), "\n";
+try {
+    $bundle->setTransform('no_such_function');
+} catch (FluentPhp\Exception $e) {
+    echo $e->getMessage(), "\n";
+}
+?>
+===DONE===
+--EXPECT--
+--- 1: literal text only ---
+Bonjour Anna<NNBSP>!
+C’est O'Brien !<NNBSP>!
+--- 2: pure callables are cached by text ---
+int(4)
+Bonjour Ben<NNBSP>!
+int(2)
+--- 3: combined with pseudo-localization ---
+ƁǾǿȠĴǾǿŬŭŘ Anna !
+--- 4: results that are not strings keep the text ---
+Bonjour Anna !
+--- 5: exceptions ---
+Resolution failed for message "plain" with error: transform() threw RuntimeException: boom
+RuntimeException: boom
+string(5) "plain"
+--- 6: removing the transform ---
+Bonjour Anna !
+setTransform() expects a valid callable.
+===DONE===
@@ -0,0 +1,29 @@
+--TEST--
+INI fluent.use_isolating sets the default bidi isolation
+--INI--
+fluent.use_isolating=1
+--FILE--
+<?php
+$resource = 'greeting = Hello, { $name }!' . "\n";
+
+$bundle = new FluentPhp\FluentBundle('en');
+$bundle->addResource($resource);
+var_dump(bin2hex($bundle->formatPattern('greeting', ['name' => 'Anna'])));
+
+$plain = new FluentPhp\FluentBundle('en', ['useIsolating' => false]);
+$plain->addResource($resource);
+var_dump($plain->formatPattern('greeting', ['name' => 'Anna']));
+
+ini_set('fluent.use_isolating', '0');
+$later = new FluentPhp\FluentBundle('en');
+$later->addResource($resource);
+var_dump($later->formatPattern('greeting', ['name' => 'Anna']));
+var_dump(bin2hex($bundle->formatPattern('greeting', ['name' => 'Anna'])));
+?>
+===DONE===
+--EXPECT--
+string(36) "48656c6c6f2c20e281a8416e6e61e281a921"
+string(12) "Hello, Anna!"
+string(12) "Hello, Anna!"
+string(36) "48656c6c6f2c20e281a8416e6e61e281a921"
+===DONE===